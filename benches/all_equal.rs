@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use quantor::quantifiers::{all_equal, all_equal_copy};
+
+fn bench_all_equal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("all_equal");
+
+    for len in [1_000usize, 100_000, 1_000_000] {
+        let bytes = vec![7u8; len];
+        let words = vec![7u32; len];
+
+        group.bench_with_input(BenchmarkId::new("generic/u8", len), &bytes, |b, xs| {
+            b.iter(|| all_equal(black_box(xs)))
+        });
+        group.bench_with_input(BenchmarkId::new("copy/u8", len), &bytes, |b, xs| {
+            b.iter(|| all_equal_copy(black_box(xs)))
+        });
+        group.bench_with_input(BenchmarkId::new("generic/u32", len), &words, |b, xs| {
+            b.iter(|| all_equal(black_box(xs)))
+        });
+        group.bench_with_input(BenchmarkId::new("copy/u32", len), &words, |b, xs| {
+            b.iter(|| all_equal_copy(black_box(xs)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_all_equal);
+criterion_main!(benches);
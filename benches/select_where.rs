@@ -0,0 +1,23 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use quantor::quantifiers::{par_select_where, select_where};
+
+fn bench_select_where(c: &mut Criterion) {
+    let mut group = c.benchmark_group("select_where");
+
+    for len in [1_000usize, 100_000, 1_000_000] {
+        let numbers: Vec<u64> = (0..len as u64).collect();
+        let is_match = |x: &u64| x.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 63 == 0;
+
+        group.bench_with_input(BenchmarkId::new("sequential", len), &numbers, |b, xs| {
+            b.iter(|| select_where(black_box(xs), is_match))
+        });
+        group.bench_with_input(BenchmarkId::new("parallel", len), &numbers, |b, xs| {
+            b.iter(|| par_select_where(black_box(xs), is_match))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_select_where);
+criterion_main!(benches);
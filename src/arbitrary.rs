@@ -0,0 +1,140 @@
+//! # quantor: Arbitrary
+//!
+//! Implementations of [`Arbitrary`] for the error types, for property-based testing and fuzzing of
+//! code that handles quantifier failures.
+//!
+//! Enable the `arbitrary` feature to activate this module. When the feature is off, nothing
+//! from this module is compiled.
+//!
+//! Fields holding `&'static str` (context messages and labels) are drawn from a small fixed set,
+//! since arbitrary data cannot be borrowed for `'static`.
+
+use std::borrow::Cow;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{error::{Comparison, Mismatch, QuantorKind, Side}, QuantorError};
+
+/// The `&'static str` values used for labels and context messages.
+const STATIC_STRS: [&str; 4] = ["", "xs", "validating input", "&items[..]"];
+
+impl<'a> Arbitrary<'a> for QuantorKind {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use QuantorKind::*;
+
+        u.choose(&[
+            Forall, Exists, None, Implies, ForallWithAggregate, ExactlyOne, ExactlyOneOf, ExactlyN,
+            AtLeast, AtMost, AllEqual, AllClose, AllDistinct, Pairwise, Windowed, Chunked, Cyclic,
+            StrictlyIncreasing, Transitions, Endpoints, SequenceEqual, ForallEq, ForAllExists,
+            ExistsForAll, ForallForall, ExistsExists, ForallNested, Subset, Superset, Disjoint, Custom,
+        ]).copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Comparison {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[Comparison::Exactly, Comparison::AtLeast, Comparison::AtMost]).copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Side {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[Side::Left, Side::Right]).copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Mismatch {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Mismatch { index: u.arbitrary()?, expected: u.arbitrary()?, found: u.arbitrary()? })
+    }
+}
+
+/// Generates any variant, including wrapping variants with arbitrarily nested sources.
+///
+/// Nesting terminates once the input data is exhausted, as the variant choice then falls back
+/// to the non-recursive `PredicateFailed`.
+///
+/// ## Example
+/// A property test checking that the diagnostics of any error never panic and are consistent:
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use quantor::{error::QuantorResultExt, QuantorError};
+///
+/// let mut seed = 0x2545_f491_4f6c_dd1du64;
+///
+/// for _ in 0..1_000 {
+///     let bytes: Vec<u8> = (0..64).map(|_| {
+///         seed ^= seed << 13;
+///         seed ^= seed >> 7;
+///         seed ^= seed << 17;
+///         seed as u8
+///     }).collect();
+///
+///     let error = QuantorError::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+///
+///     let _ = error.to_string();
+///     assert_eq!(error.cmp(&error), Ordering::Equal);
+///
+///     let result: Result<(), QuantorError> = Err(error);
+///     assert_eq!(result.failing_indices().first().copied(), result.failing_index());
+/// }
+/// ```
+impl<'a> Arbitrary<'a> for QuantorError {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use QuantorError::*;
+
+        let error = match u.int_in_range(0..=29u8)? {
+            0 => PredicateFailed { kind: u.arbitrary()?, index: u.arbitrary()? },
+            1 => PredicateFailedWithContext {
+                kind: u.arbitrary()?,
+                index: u.arbitrary()?,
+                start: u.arbitrary()?,
+                nearby: u.arbitrary()?,
+                len: u.arbitrary()?,
+            },
+            2 => PredicatePanicked { kind: u.arbitrary()?, index: u.arbitrary()?, message: u.arbitrary()? },
+            3 => PredicateFailedReason { kind: u.arbitrary()?, index: u.arbitrary()?, reason: u.arbitrary()? },
+            4 => EmptyInput { kind: u.arbitrary()? },
+            5 => NestedEmptyInput { kind: u.arbitrary()?, side: u.arbitrary()? },
+            6 => NoMatch { kind: u.arbitrary()? },
+            7 => UnexpectedMatch { kind: u.arbitrary()?, index: u.arbitrary()? },
+            8 => NotExactlyOneOf { kind: u.arbitrary()?, index: u.arbitrary()?, matched: u.arbitrary()? },
+            9 => ImplicationViolated { kind: u.arbitrary()?, index: u.arbitrary()? },
+            10 => AggregateFailed { kind: u.arbitrary()?, len: u.arbitrary()? },
+            11 => NotAllEqual { kind: u.arbitrary()?, index: u.arbitrary()? },
+            12 => PairwiseFailed { kind: u.arbitrary()?, index: u.arbitrary()? },
+            13 => DuplicateAt { kind: u.arbitrary()?, index: u.arbitrary()? },
+            14 => DecreaseAt { kind: u.arbitrary()?, index: u.arbitrary()? },
+            15 => ForAllExistsFailed { kind: u.arbitrary()?, outer_index: u.arbitrary()? },
+            16 => ExistsForAllFailed { kind: u.arbitrary()?, outer_index: u.arbitrary()? },
+            17 => CrossPairFailed { kind: u.arbitrary()?, left_index: u.arbitrary()?, right_index: u.arbitrary()? },
+            18 => NestedPredicateFailed { kind: u.arbitrary()?, outer_index: u.arbitrary()?, inner_index: u.arbitrary()? },
+            19 => ExactlyNFailed { kind: u.arbitrary()?, found: u.arbitrary()?, expected: u.arbitrary()?, comparison: u.arbitrary()? },
+            20 => TransitionLimitExceeded { kind: u.arbitrary()?, index: u.arbitrary()?, limit: u.arbitrary()? },
+            21 => SequenceMismatch { kind: u.arbitrary()?, index: u.arbitrary()? },
+            22 => LengthMismatch { kind: u.arbitrary()?, found: u.arbitrary()?, expected: u.arbitrary()? },
+            23 => ElementsMismatched { kind: u.arbitrary()?, mismatches: u.arbitrary()?, total: u.arbitrary()? },
+            24 => Custom(Cow::Owned(u.arbitrary()?)),
+            25 => CustomIndexed { msg: Cow::Owned(u.arbitrary()?), index: u.arbitrary()? },
+            26 => CustomWithSource { msg: static_str(u)?, source: u.arbitrary()? },
+            27 => ElementFailed { index: u.arbitrary()?, source: u.arbitrary()? },
+            28 => Labeled { label: static_str(u)?, source: u.arbitrary()? },
+            _ => LabeledNested {
+                outer: static_str(u)?,
+                outer_var: static_str(u)?,
+                inner: static_str(u)?,
+                inner_var: static_str(u)?,
+                source: u.arbitrary()?,
+            },
+        };
+
+        Ok(error)
+    }
+}
+
+/// Picks one of the [`STATIC_STRS`].
+fn static_str(u: &mut Unstructured<'_>) -> Result<&'static str> {
+    u.choose(&STATIC_STRS).copied()
+}
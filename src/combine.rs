@@ -0,0 +1,99 @@
+//! # quantor: Combinators
+//!
+//! This module provides [`all_of`] and [`any_of`], which combine the results of several
+//! independent quantifier checks into one. Unlike `a.is_ok() && b.is_ok()`, the individual
+//! errors are kept in a [`QuantorErrors`] collection.
+//!
+//! ## Example
+//! ```
+//! use quantor::{forall, exists, combine::all_of};
+//!
+//! let ports = vec![80, 443, 70000];
+//!
+//! let result = all_of([
+//!     forall(&ports, |p| *p <= 65535),
+//!     exists(&ports, |p| *p == 443),
+//!     forall(&ports, |p| *p != 0),
+//! ]);
+//!
+//! assert_eq!(result.unwrap_err().len(), 1);
+//! ```
+
+use crate::{error::QuantorKind, QuantorError, QuantorErrors};
+
+/// A reusable check over a whole slice, as accepted by `QuantorExt::validate_all`.
+pub type SliceCheck<'a, T> = &'a dyn Fn(&[T]) -> Result<(), QuantorError>;
+
+/// Succeeds if every result is a success, collecting all failures otherwise.
+///
+/// Equivalent to **_∀r ∈ results: r = Ok_**.
+///
+/// All results are inspected, so every failure ends up in the returned collection.
+/// ## Arguments
+/// - `results` - The results of the individual checks.
+/// ## Returns
+/// - `Ok(())` if all results are `Ok`, including when there are none.
+/// - `Err(QuantorErrors)` containing every error, in order.
+/// ## Example
+/// ```
+/// use quantor::{forall, none, combine::all_of};
+///
+/// let nums = vec![1, 2, 3];
+///
+/// assert!(all_of([forall(&nums, |x| *x > 0), none(&nums, |x| *x > 5)]).is_ok());
+///
+/// let errors = all_of([forall(&nums, |x| *x > 1), none(&nums, |x| *x > 2)]).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[inline]
+pub fn all_of<I>(results: I) -> Result<(), QuantorErrors>
+where
+    I: IntoIterator<Item = Result<(), QuantorError>>,
+{
+    results
+        .into_iter()
+        .filter_map(Result::err)
+        .collect::<QuantorErrors>()
+        .into_result()
+}
+
+/// Succeeds if at least one result is a success, collecting all failures otherwise.
+///
+/// Equivalent to **_∃r ∈ results: r = Ok_**.
+/// ## Arguments
+/// - `results` - The results of the individual checks.
+/// ## Returns
+/// - `Ok(())` if any result is `Ok`.
+/// - `Err(QuantorErrors)` containing every error, in order, if all results are errors.
+/// - `Err(QuantorErrors)` containing a single `QuantorError::EmptyInput` if there are no results.
+/// ## Example
+/// ```
+/// use quantor::{forall, exists, combine::any_of};
+///
+/// let nums = vec![1, 2, 3];
+///
+/// assert!(any_of([forall(&nums, |x| *x > 1), exists(&nums, |x| *x == 2)]).is_ok());
+///
+/// let errors = any_of([forall(&nums, |x| *x > 1), exists(&nums, |x| *x == 5)]).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[inline]
+pub fn any_of<I>(results: I) -> Result<(), QuantorErrors>
+where
+    I: IntoIterator<Item = Result<(), QuantorError>>,
+{
+    let mut errors = QuantorErrors::new();
+
+    for result in results {
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if errors.is_empty() {
+        errors.push(QuantorError::EmptyInput { kind: QuantorKind::Custom });
+    }
+
+    Err(errors)
+}
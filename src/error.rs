@@ -7,14 +7,101 @@
 //! enough detail to aid in diagnostics or debugging. Most errors include the index of the
 //! failing element or pair to allow precise localization of the failure.
 //!
+//! Checks that report several failures at once use the [`QuantorErrors`] collection.
+//!
 //! The accompanying extension trait [`QuantorResultExt`] provides convenience methods for
-//! extracting diagnostic information from `Result<(), QuantorError>` values, which are returned
+//! extracting diagnostic information from `Result<T, QuantorError>` values, which are returned
 //! by all fallible quantifier functions.
 
-use std::fmt;
+use std::{borrow::Cow, cmp::Ordering, fmt};
 
 /// Error type returned by fallible quantifier evaluations in `quantor`.
-#[derive(PartialEq, Eq)]
+///
+/// ## Ordering
+/// Errors are ordered so that sorting a list of failures gives a stable, readable report:
+/// 1. by [`kind`](QuantorError::kind), in the declaration order of [`QuantorKind`],
+/// 2. then by failing index, with errors that carry no index sorted last,
+/// 3. then by [`code`](QuantorError::code), and finally by the remaining fields.
+///
+/// The ordering is consistent with `Eq`: two errors compare as `Equal` only if they are equal.
+///
+/// ```
+/// use quantor::{forall, exists, none, error::QuantorKind, QuantorError};
+///
+/// let nums = [1, 2, 3];
+///
+/// let mut errors = vec![
+///     none(&nums, |x| *x == 1).unwrap_err(),
+///     forall(&nums, |x| *x < 3).unwrap_err(),
+///     exists(&nums, |x| *x > 5).unwrap_err(),
+///     forall(&nums, |x| *x < 2).unwrap_err(),
+/// ];
+/// errors.sort();
+///
+/// let order: Vec<_> = errors.iter().map(|e| (e.kind().to_string(), e.code())).collect();
+/// assert_eq!(order, vec![
+///     ("forall".to_string(), "QE_PREDICATE_FAILED"),
+///     ("forall".to_string(), "QE_PREDICATE_FAILED"),
+///     ("exists".to_string(), "QE_NO_MATCH"),
+///     ("none".to_string(), "QE_UNEXPECTED_MATCH"),
+/// ]);
+/// assert_eq!(errors[0], QuantorError::predicate_failed(QuantorKind::Forall, 1));
+/// ```
+///
+/// ## Symbolic display
+/// The alternate format `{:#}` renders a compact form led by the quantifier's
+/// [`symbol`](QuantorKind::symbol), for notebook or markdown output. A plain `PredicateFailed` is
+/// summarized as `"<symbol> predicate failed at index <index>"`; every other variant keeps its own
+/// message, prefixed with the symbol. The plain `{}` format stays word-based.
+///
+/// ```
+/// use quantor::{forall, exists, none, quantify_labeled, quantifiers::forall_reasoned, QuantorError};
+///
+/// let nums = [1, 2, 3, 4];
+///
+/// let err = forall(&nums, |x| *x < 4).unwrap_err();
+/// assert_eq!(format!("{:#}", err), "∀ predicate failed at index 3");
+/// assert_eq!(format!("{}", err), "Predicate failed for element at index 3 of quantifier forall.");
+///
+/// let err = exists(&nums, |x| *x > 4).unwrap_err();
+/// assert_eq!(format!("{:#}", err), "∃ No element satisfied the predicate for quantifier exists.");
+///
+/// let err = none(&nums, |x| *x == 2).unwrap_err();
+/// assert_eq!(format!("{:#}", err), "∄ Unexpected match found at index 1 of quantifier none.");
+///
+/// let err = QuantorError::custom_at("`café` is not ASCII", 1);
+/// assert_eq!(format!("{:#}", err), "λ `café` is not ASCII (at index 1)");
+///
+/// let err = forall_reasoned(&nums, |x| if *x < 3 { Ok(()) } else { Err(format!("{} is too large", x)) }).unwrap_err();
+/// assert_eq!(format!("{:#}", err), "∀ Predicate failed for element at index 2 of quantifier forall: 3 is too large");
+///
+/// let err = quantify_labeled!(forall x in &nums => *x < 4).unwrap_err();
+/// assert_eq!(format!("{:#}", err), "∀ forall over `nums` failed at index 3.");
+/// ```
+///
+/// ## Hashing
+/// `QuantorError` implements [`Hash`](std::hash::Hash) consistently with equality, so failures can
+/// be deduplicated in a `HashSet`. Custom messages are hashed by their text, regardless of whether
+/// they are borrowed or owned.
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use quantor::{forall, exists, QuantorError};
+///
+/// let batches = [vec![1, 2, 3], vec![4, 2, 3], vec![1, 2, 7]];
+///
+/// let distinct: HashSet<QuantorError> = batches
+///     .iter()
+///     .flat_map(|batch| [forall(batch, |x| *x < 3), exists(batch, |x| *x > 9)])
+///     .filter_map(Result::err)
+///     .chain([QuantorError::from("custom".to_string()), QuantorError::Custom("custom".into())])
+///     .collect();
+///
+/// // forall failing at index 2, forall failing at index 0, exists, and the custom message.
+/// assert_eq!(distinct.len(), 4);
+/// ```
+#[derive(PartialEq, Eq, Hash)]
 pub enum QuantorError {
     /// Returned when a predicate fails during a `forall` check.
     PredicateFailed {
@@ -23,11 +110,52 @@ pub enum QuantorError {
         /// The index of the first failing element.
         index: usize
     },
+    /// Returned when a predicate fails during `forall_context`, carrying the neighboring elements.
+    PredicateFailedWithContext {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the first failing element.
+        index: usize,
+        /// The index of the first element in `nearby`.
+        start: usize,
+        /// The `Debug` output of the elements around the failure, including the failing element.
+        nearby: Vec<String>,
+        /// The length of the checked slice.
+        len: usize,
+    },
+    /// Returned when a predicate panics during `forall_catching`.
+    PredicatePanicked {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the element for which the predicate panicked.
+        index: usize,
+        /// The panic message, if the payload was a string.
+        message: Option<String>,
+    },
+    /// Returned when a predicate of `forall_reasoned` rejects an element with a reason.
+    PredicateFailedReason {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the first failing element.
+        index: usize,
+        /// The reason returned by the predicate.
+        reason: String,
+    },
     /// Returned when no elements are given.
     EmptyInput {
         /// The kind of quantifier that threw this error.
         kind: QuantorKind
     },
+    /// Returned when a nested quantifier fails because one of its collections is empty.
+    ///
+    /// If `side` is [`Side::Right`], the left-hand collection had elements, so the failure is
+    /// caused solely by the empty right-hand collection.
+    NestedEmptyInput {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The collection that was empty.
+        side: Side,
+    },
     /// Returned when no element satisfies the predicate in an `exists` check.
     NoMatch {
         /// The kind of quantifier that threw this error.
@@ -40,6 +168,29 @@ pub enum QuantorError {
         /// The index of the violating element.
         index: usize
     },
+    /// Returned when an element does not satisfy exactly one of the predicates in `exactly_one_of`.
+    NotExactlyOneOf {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the first offending element.
+        index: usize,
+        /// The number of predicates that matched the element.
+        matched: usize,
+    },
+    /// Returned when an element satisfies the premise but not the conclusion of `implies`.
+    ImplicationViolated {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the first element violating the implication.
+        index: usize
+    },
+    /// Returned when every element passed, but the folded aggregate failed its check in `forall_with_aggregate`.
+    AggregateFailed {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The number of elements folded into the aggregate.
+        len: usize
+    },
     /// Returned when not all elements are equal in `all_equal`.
     NotAllEqual {
         /// The kind of quantifier that threw this error.
@@ -54,6 +205,20 @@ pub enum QuantorError {
         /// The index of the first element in the failing pair.
         index: usize
     },
+    /// Returned when `strictly_increasing` finds an element equal to its predecessor.
+    DuplicateAt {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the repeated element.
+        index: usize
+    },
+    /// Returned when `strictly_increasing` finds an element smaller than (or incomparable to) its predecessor.
+    DecreaseAt {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the element that breaks the ascending order.
+        index: usize
+    },
     /// Returned when a `forallexists` condition fails.
     ForAllExistsFailed {
         /// The kind of quantifier that threw this error.
@@ -68,7 +233,25 @@ pub enum QuantorError {
         /// The index of the outer (left) element that failed.
         outer_index: usize
     },
-    /// Returned when the number of matches does not equal the expected count.
+    /// Returned when a pair of elements from two collections fails a `forallforall` predicate.
+    CrossPairFailed {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the element in the left-hand collection.
+        left_index: usize,
+        /// The index of the element in the right-hand collection.
+        right_index: usize,
+    },
+    /// Returned when an element of an inner collection fails a `forall_nested` predicate.
+    NestedPredicateFailed {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the inner collection within the outer collection.
+        outer_index: usize,
+        /// The index of the failing element within its inner collection.
+        inner_index: usize,
+    },
+    /// Returned when the number of matches does not satisfy the expected count.
     ExactlyNFailed {
         /// The kind of quantifier that threw this error.
         kind: QuantorKind,
@@ -76,16 +259,112 @@ pub enum QuantorError {
         found: usize,
         /// Number of matches expected.
         expected: usize,
+        /// How `found` was compared against `expected`.
+        comparison: Comparison,
+    },
+    /// Returned when a predicate changes its result between adjacent elements more often than allowed.
+    TransitionLimitExceeded {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The index of the first element in the pair that exceeded the limit.
+        index: usize,
+        /// The maximum number of allowed transitions.
+        limit: usize,
+    },
+    /// Returned when two sequences differ at some position.
+    SequenceMismatch {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The first index at which the sequences differ.
+        index: usize,
+    },
+    /// Returned when two sequences that should be equal have different lengths.
+    LengthMismatch {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// Length of the checked sequence.
+        found: usize,
+        /// Length of the expected sequence.
+        expected: usize,
+    },
+    /// Returned when two sequences differ element-wise in `forall_eq`.
+    ElementsMismatched {
+        /// The kind of quantifier that threw this error.
+        kind: QuantorKind,
+        /// The first few mismatching positions, in order.
+        mismatches: Vec<Mismatch>,
+        /// The total number of mismatching positions, including those not listed in `mismatches`.
+        total: usize,
+    },
+    /// A catch-all error with a custom message.
+    ///
+    /// Borrowed and owned messages compare equal when their text is equal:
+    /// ```
+    /// use quantor::QuantorError;
+    ///
+    /// assert_eq!(QuantorError::from("x".to_string()), QuantorError::from("x"));
+    /// assert_eq!(QuantorError::Custom("x".into()), QuantorError::Custom(String::from("x").into()));
+    /// assert_ne!(QuantorError::from("x"), QuantorError::from("y"));
+    /// ```
+    Custom(Cow<'static, str>),
+    /// A custom error message attached to the index of the failing element.
+    ///
+    /// Intended for user-defined quantifiers; see [`QuantorError::custom_at`].
+    CustomIndexed {
+        /// The message describing the failure.
+        msg: Cow<'static, str>,
+        /// The index of the failing element.
+        index: usize,
+    },
+    /// Wraps another error with a message describing what was being validated.
+    ///
+    /// Produced by [`QuantorResultExt::context`].
+    CustomWithSource {
+        /// The message describing the context of the failure.
+        msg: &'static str,
+        /// The original error.
+        source: Box<QuantorError>,
+    },
+    /// Wraps the error returned for a single element by `for_each_checked` or `forall_valid`, together with its index.
+    ElementFailed {
+        /// The index of the element whose check failed.
+        index: usize,
+        /// The error returned by the check.
+        source: Box<QuantorError>,
+    },
+    /// Wraps another error with the name of the collection it was evaluated over.
+    ///
+    /// Produced by [`quantify_labeled!`](crate::quantify_labeled).
+    Labeled {
+        /// The collection expression as written at the call site.
+        label: &'static str,
+        /// The original error.
+        source: Box<QuantorError>,
+    },
+    /// Wraps a nested quantifier error with the names of both collections and their bound variables.
+    ///
+    /// Produced by the nested arms of [`quantify_labeled!`](crate::quantify_labeled).
+    LabeledNested {
+        /// The outer collection expression as written at the call site.
+        outer: &'static str,
+        /// The variable bound to elements of the outer collection.
+        outer_var: &'static str,
+        /// The inner collection expression as written at the call site.
+        inner: &'static str,
+        /// The variable bound to elements of the inner collection.
+        inner_var: &'static str,
+        /// The original error.
+        source: Box<QuantorError>,
     },
-    /// A catch-all error with a static message.
-    Custom(&'static str),
 }
 
 /// Represents the type of quantifier used in a logical check.
 ///
 /// Used in error variants to indicate which quantifier produced the failure,
 /// and for introspection via [`QuantorError::kind()`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Kinds are ordered by their declaration order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum QuantorKind {
     /// Universal quantifier (`forall`): all elements must satisfy the predicate.
     Forall,
@@ -93,29 +372,122 @@ pub enum QuantorKind {
     Exists,
     /// Negated existential quantifier (`none`): no elements may satisfy the predicate.
     None,
+    /// Material implication (`implies`): every element satisfying the premise also satisfies the conclusion.
+    Implies,
+    /// True if all elements satisfy the predicate and their aggregate passes a final check.
+    ForallWithAggregate,
     /// True if exactly one element satisfies the predicate.
     ExactlyOne,
+    /// True if every element satisfies exactly one predicate out of a set.
+    ExactlyOneOf,
     /// True if the number of matching elements equals the given count.
     ExactlyN,
+    /// True if the number of matching elements is at least the given count.
+    AtLeast,
+    /// True if the number of matching elements is at most the given count.
+    AtMost,
     /// True if all elements are equal (via `PartialEq`).
     AllEqual,
+    /// True if all elements are within a tolerance of each other.
+    AllClose,
+    /// True if no element occurs more than once.
+    AllDistinct,
     /// True if every adjacent pair satisfies the predicate.
     Pairwise,
+    /// True if every sliding window of adjacent elements satisfies the predicate.
+    Windowed,
+    /// True if every non-overlapping chunk of elements satisfies the predicate.
+    Chunked,
+    /// True if every adjacent pair, including the pair of the last and first element, satisfies the predicate.
+    Cyclic,
+    /// True if every element is strictly greater than its predecessor.
+    StrictlyIncreasing,
+    /// True if the predicate changes its result between adjacent elements at most `n` times.
+    Transitions,
+    /// True if a predicate holds for the first and last element.
+    Endpoints,
+    /// True if two sequences are element-wise equal.
+    SequenceEqual,
+    /// True if two sequences are element-wise equal, reporting every mismatch.
+    ForallEq,
     /// Nested quantifier: for every element in `A`, some element in `B` satisfies a predicate.
     ForAllExists,
     /// Nested quantifier: some element in `A` satisfies a predicate for all elements in `B`.
     ExistsForAll,
+    /// Nested quantifier: every pair of elements from `A` and `B` satisfies a predicate.
+    ForallForall,
+    /// Nested quantifier: some pair of elements from `A` and `B` satisfies a predicate.
+    ExistsExists,
+    /// Nested quantifier: every element of every inner collection satisfies a predicate.
+    ForallNested,
+    /// Set relation: every element in `A` appears in `B`.
+    Subset,
+    /// Set relation: every element in `B` appears in `A`.
+    Superset,
+    /// Set relation: no element in `A` appears in `B`.
+    Disjoint,
     /// Fallback for custom or user-defined logic.
     Custom,
 }
 
-/// Extension trait for `Result<(), QuantorError>` to simplify diagnostics.
+/// A single mismatching position reported by [`QuantorError::ElementsMismatched`].
+///
+/// Values are stored in their `Display` form. A side is `None` when that sequence has no element at `index`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mismatch {
+    /// The index of the mismatching position.
+    pub index: usize,
+    /// The expected element, if the expected sequence is long enough.
+    pub expected: Option<String>,
+    /// The actual element, if the actual sequence is long enough.
+    pub found: Option<String>,
+}
+
+/// Describes how a match count is compared against the expected count in [`QuantorError::ExactlyNFailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Comparison {
+    /// The number of matches must equal the expected count.
+    Exactly,
+    /// The number of matches must be greater than or equal to the expected count.
+    AtLeast,
+    /// The number of matches must be less than or equal to the expected count.
+    AtMost,
+}
+
+/// Identifies one collection of a nested quantifier, as reported by [`QuantorError::NestedEmptyInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Side {
+    /// The left-hand (outer) collection `a`.
+    Left,
+    /// The right-hand (inner) collection `b`.
+    Right,
+}
+
+/// Extension trait for `Result<T, QuantorError>` to simplify diagnostics.
 ///
 /// This trait provides methods like `failing_index` to allow inspection of where a
 /// quantifier check failed, making it easier to debug or react programmatically.
 ///
 /// All core quantifiers return `Result<(), QuantorError>`, so these helpers
 /// are ideal for writing ergonomic test assertions or logging failure details.
+/// The trait is implemented for any success type, so value-returning quantifiers such as
+/// [`the_one`](crate::quantifiers::basic::the_one) can be inspected the same way.
+///
+/// It is also implemented for `Result<T, QuantorErrors>`, as returned by multi-failure checks such
+/// as [`all_of`](crate::combine::all_of). There, the single-value methods (`failing_index`, `kind`, ...)
+/// report on the first error in the collection that carries the information, and
+/// [`failing_indices`](QuantorResultExt::failing_indices) collects the indices of all of them.
+///
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::the_one, error::{QuantorKind, QuantorResultExt}};
+///
+/// let nums = vec![1, 2, 4];
+/// let result = the_one(&nums, |x| x % 2 == 0);
+///
+/// assert_eq!(result.failing_index(), Some(2));
+/// assert_eq!(result.kind(), Some(QuantorKind::ExactlyOne));
+/// ```
 pub trait QuantorResultExt {
     /// Returns the failing index for quantifiers that include index information,
     /// such as `forall`, `none`, `pairwise`, `all_equal`, and `existsforall`.
@@ -123,6 +495,8 @@ pub trait QuantorResultExt {
     /// - `Some(index)` if the underlying [`QuantorError`] variant includes a specific index indicating
     ///   which element caused the quantifier to fail.
     /// - `None` if the error variant does not include index information (e.g. `NoMatch`, `Custom`).
+    ///
+    /// For `forall_nested`, the index of the inner collection is returned.
     /// ## Example
     /// ```
     /// use quantor::{forall, error::QuantorResultExt};
@@ -137,6 +511,70 @@ pub trait QuantorResultExt {
     /// }
     /// ```
     fn failing_index(&self) -> Option<usize>;
+    /// Returns every failing index recorded in the result.
+    ///
+    /// Most useful for `Result<T, QuantorErrors>`, where each error contributes its index in the
+    /// order the errors were recorded. Errors without index information contribute nothing, while
+    /// `ElementsMismatched` contributes the index of every listed mismatch.
+    ///
+    /// ## Returns
+    /// - The failing indices, or an empty `Vec` if the result is a success or no error carries an index.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, exists, none, pairwise, combine::all_of, error::QuantorResultExt};
+    ///
+    /// let nums = vec![3, 1, 4, 1, 5];
+    ///
+    /// let result = all_of([
+    ///     forall(&nums, |x| *x > 1),
+    ///     pairwise(&nums, |a, b| a <= b),
+    ///     none(&nums, |x| *x == 5),
+    ///     exists(&nums, |x| *x > 9),
+    /// ]);
+    ///
+    /// assert_eq!(result.failing_indices(), vec![1, 0, 4]);
+    /// assert_eq!(result.failing_index(), Some(1));
+    ///
+    /// assert_eq!(forall(&nums, |x| *x < 5).failing_indices(), vec![4]);
+    /// assert!(forall(&nums, |x| *x > 0).failing_indices().is_empty());
+    /// ```
+    fn failing_indices(&self) -> Vec<usize>;
+    /// Returns how many elements passed the check before the failing one.
+    ///
+    /// For element-wise quantifiers that stop at the first failure, such as `forall` (and its
+    /// `forall_*` variants), `none`, `implies` or `exactly_one_of`, every element before the failing
+    /// index passed, so this equals the 0-based failing index. It is provided to make reports like
+    /// "validated 4999 rows before row 5000 failed" explicit, without mixing up 0-based indices and
+    /// 1-based counts.
+    ///
+    /// The reverse-order variants `forall_rev` and `none_rev` report indices into the original
+    /// slice, but share their kinds with `forall` and `none`, so the returned count is only
+    /// meaningful for them as `len - 1 - index`.
+    ///
+    /// ## Returns
+    /// - `Some(count)` for failures of the element-wise quantifiers above, and for `ElementFailed`.
+    /// - `None` if the result is a success, or the reported index is not the first failing element,
+    ///   e.g. the second match of `exactly_one`, the first repeated element of `all_distinct`, or
+    ///   an offending element of `disjoint`.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, exists, exactly_one, error::QuantorResultExt};
+    ///
+    /// let rows: Vec<u32> = (1..=6000).collect();
+    /// let result = forall(&rows, |row| *row < 5000);
+    ///
+    /// let passed = result.passed_count().unwrap();
+    /// assert_eq!(format!("validated {} rows before row {} failed", passed, passed + 1),
+    ///            "validated 4999 rows before row 5000 failed");
+    ///
+    /// assert_eq!(exists(&rows, |row| *row == 0).passed_count(), None);
+    ///
+    /// // The index of the second match does not count passed elements.
+    /// assert_eq!(exactly_one(&rows, |row| *row % 2 == 0).passed_count(), None);
+    /// ```
+    fn passed_count(&self) -> Option<usize>;
     /// Returns the number of elements that matched the predicate,
     /// if available from the underlying [`QuantorError`] variant.
     ///
@@ -161,167 +599,1529 @@ pub trait QuantorResultExt {
     /// }
     /// ```
     fn match_count(&self) -> Option<usize>;
-}
-
-impl fmt::Display for QuantorError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        use QuantorError::*;
-
-        match self {
-            PredicateFailed { kind, index } => write!(f, "Predicate failed for element at index {} of quantifier {}.", index, kind),
-            EmptyInput { kind } => write!(f, "Empty input for quantifier {}.", kind),
-            NoMatch { kind } => write!(f, "No element satisfied the predicate for quantifier {}.", kind),
-            UnexpectedMatch { kind, index } => write!(f, "Unexpected match found at index {} of quantifier {}.", index, kind),
-            NotAllEqual { kind, index } => write!(f, "Element at index {} of quantifier {} does not match the first element.", index, kind),
-            PairwiseFailed { kind, index } => write!(f, "Predicate failed for adjacent pair starting at index {} for quantifier {}.", index, kind),
-            ForAllExistsFailed { kind, outer_index } => write!(f, "Element at index {} in the outer collection failed to match any right-hand value for quantifier {}.", outer_index, kind),
-            ExistsForAllFailed { kind, outer_index } => write!(f, "Element at index {} in the left-hand collection failed the universal condition for quantifier {}.", outer_index, kind),
-            ExactlyNFailed { kind, found, expected } => write!(f, "Expected {} elements to match, found {} for quantifier {}.", expected, found, kind),
-            Custom(msg) => write!(f, "{}", msg),
-        }
-    }
-}
-
-impl fmt::Display for QuantorKind {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = match self {
-            QuantorKind::Forall => "forall",
-            QuantorKind::Exists => "exists",
-            QuantorKind::None => "none",
-            QuantorKind::ExactlyOne => "exactly_one",
-            QuantorKind::Pairwise => "pairwise",
-            QuantorKind::ExactlyN => "exactly_n",
-            QuantorKind::AllEqual => "all_equal",
-            QuantorKind::ForAllExists => "forallexists",
-            QuantorKind::ExistsForAll => "existsforall",
-            QuantorKind::Custom => "custom",
-        };
-        write!(f, "{}", name)
-    }
-}
-
-impl QuantorError {
-    /// Returns `true` if the quantifier failed due to a predicate mismatch.
-    ///
-    /// Useful for identifying simple predicate failures, such as those from `forall` or `exactly_one`.
+    /// Returns the number of matches the quantifier expected,
+    /// if available from the underlying [`QuantorError`] variant.
     ///
     /// ## Returns
-    /// - `true` if the error variant is [`QuantorError::PredicateFailed`].
-    /// - `false` otherwise.
+    /// - `Some(count)` if the error contains an expected count (e.g. `ExactlyNFailed`).
+    /// - `None` for other kinds of errors that do not track match counts.
     ///
     /// ## Example
     /// ```
-    /// use quantor::{forall, error::QuantorResultExt};
+    /// use quantor::{quantifiers::at_least, error::QuantorResultExt};
     ///
     /// let nums = vec![1, 2, 3];
-    /// let result = forall(&nums, |x| *x < 3);
+    /// let result = at_least(&nums, 2, |x| x % 2 == 0);
     ///
-    /// assert!(result.is_err());
-    /// assert!(result.unwrap_err().is_predicate_failed());
+    /// assert_eq!(result.match_count(), Some(1));
+    /// assert_eq!(result.expected_count(), Some(2));
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn is_predicate_failed(&self) -> bool {
-        matches!(self, QuantorError::PredicateFailed {..})
-    }
+    fn expected_count(&self) -> Option<usize>;
 
-    /// Returns `true` if the quantifier failed because no element matched the predicate.
+    /// Returns the kind of quantifier that produced the error.
     ///
-    /// Typically used with `exists` or `existsforall` where at least one match is expected.
+    /// ## Returns
+    /// - `Some(kind)` if the result is an error, see [`QuantorError::kind`].
+    /// - `None` if the result is a success.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{exists, error::{QuantorKind, QuantorResultExt}};
+    ///
+    /// let nums = vec![1, 3];
+    ///
+    /// assert_eq!(exists(&nums, |x| x % 2 == 0).kind(), Some(QuantorKind::Exists));
+    /// assert_eq!(exists(&nums, |x| *x == 3).kind(), None);
+    /// ```
+    fn kind(&self) -> Option<QuantorKind>;
+
+    /// Attaches a message to the error, keeping the original error as its source.
+    ///
+    /// The resulting error displays as `"<msg>: <original message>"` and still reports the
+    /// original kind, index and match count.
     ///
     /// ## Returns
-    /// - `true` if the error variant is [`QuantorError::NoMatch`].
-    /// - `false` otherwise.
+    /// - `Ok` unchanged if the result is a success.
+    /// - `Err(QuantorError::CustomWithSource { msg, source })` wrapping the original error otherwise.
     ///
     /// ## Example
     /// ```
-    /// use quantor::{exists, error::QuantorResultExt};
+    /// use quantor::{forall, error::QuantorResultExt};
     ///
-    /// let nums = [1, 2, 3];
-    /// let result = exists(&nums, |x| *x > 10);
+    /// let ports = vec![80, 443, 70000];
+    /// let result = forall(&ports, |p| *p <= 65535).context("validating ports");
     ///
-    /// assert!(result.is_err());
-    /// assert!(result.unwrap_err().is_no_match());
+    /// assert_eq!(result.failing_index(), Some(2));
+    /// assert!(result.unwrap_err().to_string().starts_with("validating ports: Predicate failed"));
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn is_no_match(&self) -> bool {
-        matches!(self, QuantorError::NoMatch { .. })
-    }
+    fn context(self, msg: &'static str) -> Self
+    where
+        Self: Sized;
 
-    /// Returns the [`QuantorKind`] associated with this error.
+    /// Runs a second check only if this result is a success.
     ///
-    /// Allows inspection of which quantifier failed, regardless of the specific error variant.
+    /// Intended for validation pipelines where a later check only makes sense once an earlier one
+    /// passed. Equivalent to `Result::and_then` with the success value ignored.
     ///
     /// ## Returns
-    /// - A [`QuantorKind`] value corresponding to the quantifier that produced the error.
-    /// - [`QuantorKind::Custom`] for generic errors.
+    /// - The result of `next` if this result is a success.
+    /// - The original error otherwise, without calling `next`.
     ///
     /// ## Example
     /// ```
-    /// use quantor::{forall, error::{QuantorKind, QuantorResultExt}};
+    /// use quantor::{forall, pairwise, error::QuantorResultExt};
     ///
-    /// let nums = [1, 2, 3];
-    /// let result = forall(&nums, |x| *x > 3);
+    /// let nums = vec![1, 2, 0, 4];
     ///
-    /// assert_eq!(result.unwrap_err().kind(), QuantorKind::Forall);
+    /// let result = forall(&nums, |x| *x > 0).and_also(|| pairwise(&nums, |a, b| a < b));
+    /// assert_eq!(result.failing_index(), Some(2));
+    ///
+    /// // The second check is never run after the first one fails.
+    /// let mut ran = false;
+    /// let result = forall(&nums, |x| *x > 0).and_also(|| {
+    ///     ran = true;
+    ///     pairwise(&nums, |a, b| a < b)
+    /// });
+    ///
+    /// assert!(result.is_err());
+    /// assert!(!ran);
+    ///
+    /// let sorted = vec![1, 2, 4];
+    /// assert!(forall(&sorted, |x| *x > 0).and_also(|| pairwise(&sorted, |a, b| a < b)).is_ok());
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn kind(&self) -> QuantorKind {
-        match self {
-            QuantorError::PredicateFailed { kind, .. } => *kind,
-            QuantorError::EmptyInput { kind } => *kind,
-            QuantorError::NoMatch { kind, .. } => *kind,
-            QuantorError::UnexpectedMatch { kind, .. } => *kind,
-            QuantorError::NotAllEqual { kind, .. } => *kind,
-            QuantorError::PairwiseFailed { kind, .. } => *kind,
-            QuantorError::ForAllExistsFailed { kind, .. } => *kind,
-            QuantorError::ExistsForAllFailed { kind, .. } => *kind,
-            QuantorError::ExactlyNFailed { kind, .. } => *kind,
-            QuantorError::Custom(_) => QuantorKind::Custom,
+    fn and_also<F>(self, next: F) -> Self
+    where
+        Self: Sized,
+        F: FnOnce() -> Self;
+}
+
+impl fmt::Display for QuantorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.render_symbolic(f)
+        } else {
+            self.render(f, false)
         }
     }
 }
 
+/// Produces the message text for a [`QuantorError`], decoupled from its [`Display`](fmt::Display) output.
+///
+/// This is the extension point for localized or otherwise customized messages: implement it for a
+/// translation table and pass it to [`QuantorError::message`]. The built-in `Display` output always
+/// stays in English, so a formatter can fall back to `error.to_string()` for variants it does not cover.
+///
+/// Any `Fn(&QuantorError) -> String` closure is a formatter.
+///
+/// Wrapping variants such as [`QuantorError::Labeled`] and [`QuantorError::CustomWithSource`] are
+/// passed to the formatter as a whole; a formatter that translates them should call
+/// [`QuantorError::message`] on the wrapped error itself.
+///
+/// ## Example
+/// ```
+/// use quantor::{forall, error::MessageFormatter, QuantorError};
+///
+/// struct German;
+///
+/// impl MessageFormatter for German {
+///     fn format(&self, error: &QuantorError) -> String {
+///         match error {
+///             QuantorError::PredicateFailed { kind, index } => format!("Prädikat für Element {} von {} nicht erfüllt.", index, kind),
+///             other => other.to_string(),
+///         }
+///     }
+/// }
+///
+/// let err = forall(&[1, 2, 3], |x| *x < 3).unwrap_err();
+///
+/// assert_eq!(err.message(&German), "Prädikat für Element 2 von forall nicht erfüllt.");
+/// assert_eq!(err.to_string(), "Predicate failed for element at index 2 of quantifier forall.");
+/// ```
+pub trait MessageFormatter {
+    /// Returns the message text for `error`.
+    fn format(&self, error: &QuantorError) -> String;
+}
 
-impl std::error::Error for QuantorError {}
-
-impl From<&'static str> for QuantorError {
-    fn from(msg: &'static str) -> Self {
-        QuantorError::Custom(msg)
+impl<F> MessageFormatter for F
+where
+    F: Fn(&QuantorError) -> String,
+{
+    #[inline]
+    fn format(&self, error: &QuantorError) -> String {
+        self(error)
     }
 }
 
-impl From<String> for QuantorError {
-    fn from(msg: String) -> Self {
-        QuantorError::Custom(Box::leak(msg.into_boxed_str()))
+/// ANSI escape sequence used for quantifier names by `QuantorError::colored`.
+const KIND_COLOR: &str = "\x1b[1;36m";
+/// ANSI escape sequence used for indices and counts by `QuantorError::colored`.
+const INDEX_COLOR: &str = "\x1b[33m";
+/// ANSI escape sequence resetting all attributes.
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// Displays a value wrapped in an ANSI escape sequence, or plain if `enabled` is `false`.
+struct Paint<T> {
+    value: T,
+    code: &'static str,
+    enabled: bool,
+}
+
+impl<T: fmt::Display> fmt::Display for Paint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.enabled {
+            write!(f, "{}{}{}", self.code, self.value, RESET_COLOR)
+        } else {
+            write!(f, "{}", self.value)
+        }
     }
 }
 
-impl fmt::Debug for QuantorError {
+/// Displays an error, optionally with ANSI colors. Used to render wrapped source errors.
+struct Rendered<'a> {
+    error: &'a QuantorError,
+    color: bool,
+}
+
+impl fmt::Display for Rendered<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self, f)
+        self.error.render(f, self.color)
     }
 }
 
-impl QuantorResultExt for Result<(), QuantorError> {
-    #[inline]
-    fn failing_index(&self) -> Option<usize> {
+impl QuantorError {
+    /// Writes the compact symbolic form used by the alternate `{:#}` format.
+    fn render_symbolic(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = self.kind().symbol();
+
         match self {
-            Err(QuantorError::PredicateFailed { index, .. }) => Some(*index),
-            Err(QuantorError::UnexpectedMatch { index, .. }) => Some(*index),
-            Err(QuantorError::PairwiseFailed { index, .. }) => Some(*index),
-            Err(QuantorError::ForAllExistsFailed { outer_index, .. }) => Some(*outer_index),
-            _ => None,
+            QuantorError::PredicateFailed { index, .. } => write!(f, "{} predicate failed at index {}", symbol, index),
+            _ => {
+                write!(f, "{} ", symbol)?;
+                self.render(f, false)
+            },
         }
     }
 
-    #[inline]
-    fn match_count(&self) -> Option<usize> {
+    /// Writes the error message, coloring quantifier names and indices if `color` is `true`.
+    fn render(&self, f: &mut fmt::Formatter<'_>, color: bool) -> fmt::Result {
+        use QuantorError::*;
+
+        let k = |kind: &QuantorKind| Paint { value: *kind, code: KIND_COLOR, enabled: color };
+        let i = |index: &usize| Paint { value: *index, code: INDEX_COLOR, enabled: color };
+
+        match self {
+            PredicateFailed { kind, index } => write!(f, "Predicate failed for element at index {} of quantifier {}.", i(index), k(kind)),
+            PredicateFailedWithContext { kind, index, start, nearby, len } => {
+                write!(f, "Predicate failed for element at index {} of quantifier {}; nearby: [", i(index), k(kind))?;
+                if *start > 0 {
+                    write!(f, ".., ")?;
+                }
+                for (offset, value) in nearby.iter().enumerate() {
+                    if offset > 0 {
+                        write!(f, ", ")?;
+                    }
+                    if start.saturating_add(offset) == *index {
+                        write!(f, "**{}**", value)?;
+                    } else {
+                        write!(f, "{}", value)?;
+                    }
+                }
+                if start.saturating_add(nearby.len()) < *len {
+                    write!(f, ", ..")?;
+                }
+                write!(f, "]")
+            },
+            PredicatePanicked { kind, index, message } => match message {
+                Some(msg) => write!(f, "Predicate panicked for element at index {} of quantifier {}: {}", i(index), k(kind), msg),
+                None => write!(f, "Predicate panicked for element at index {} of quantifier {}.", i(index), k(kind)),
+            },
+            PredicateFailedReason { kind, index, reason } => write!(f, "Predicate failed for element at index {} of quantifier {}: {}", i(index), k(kind), reason),
+            EmptyInput { kind } => write!(f, "Empty input for quantifier {}.", k(kind)),
+            NestedEmptyInput { kind, side } => write!(f, "Empty {} collection for quantifier {}.", side, k(kind)),
+            NoMatch { kind } => write!(f, "No element satisfied the predicate for quantifier {}.", k(kind)),
+            UnexpectedMatch { kind, index } => write!(f, "Unexpected match found at index {} of quantifier {}.", i(index), k(kind)),
+            NotExactlyOneOf { kind, index, matched } => write!(f, "Element at index {} matched {} predicates instead of exactly one for quantifier {}.", i(index), i(matched), k(kind)),
+            ImplicationViolated { kind, index } => write!(f, "Implication violated at index {} for quantifier {}.", i(index), k(kind)),
+            AggregateFailed { kind, len } => write!(f, "Aggregate check failed after {} elements for quantifier {}.", i(len), k(kind)),
+            NotAllEqual { kind, index } => write!(f, "Element at index {} of quantifier {} does not match the first element.", i(index), k(kind)),
+            PairwiseFailed { kind, index } => {
+                let group = match kind {
+                    QuantorKind::Windowed => "window",
+                    QuantorKind::Chunked => "chunk",
+                    _ => "adjacent pair",
+                };
+                write!(f, "Predicate failed for {} starting at index {} for quantifier {}.", group, i(index), k(kind))
+            },
+            DuplicateAt { kind, index } => write!(f, "Duplicate at index {} for quantifier {}.", i(index), k(kind)),
+            DecreaseAt { kind, index } => write!(f, "Decrease at index {} for quantifier {}.", i(index), k(kind)),
+            ForAllExistsFailed { kind, outer_index } => write!(f, "Element at index {} in the outer collection failed to match any right-hand value for quantifier {}.", i(outer_index), k(kind)),
+            ExistsForAllFailed { kind, outer_index } => write!(f, "Element at index {} in the left-hand collection failed the universal condition for quantifier {}.", i(outer_index), k(kind)),
+            CrossPairFailed { kind, left_index, right_index } => write!(f, "Predicate failed for the pair of left index {} and right index {} for quantifier {}.", i(left_index), i(right_index), k(kind)),
+            NestedPredicateFailed { kind, outer_index, inner_index } => write!(f, "Predicate failed for element at index {} of inner collection {} for quantifier {}.", i(inner_index), i(outer_index), k(kind)),
+            ExactlyNFailed { kind, found, expected, comparison } => write!(f, "Expected {}{} elements to match, found {} for quantifier {}.", comparison, i(expected), i(found), k(kind)),
+            TransitionLimitExceeded { kind, index, limit } => write!(f, "Transition at adjacent pair starting at index {} exceeded the limit of {} for quantifier {}.", i(index), i(limit), k(kind)),
+            SequenceMismatch { kind, index } => write!(f, "Sequences differ at index {} for quantifier {}.", i(index), k(kind)),
+            LengthMismatch { kind, found, expected } => write!(f, "Expected a sequence of length {}, found length {} for quantifier {}.", i(expected), i(found), k(kind)),
+            ElementsMismatched { kind, mismatches, total } => {
+                write!(f, "{} element(s) differ for quantifier {}:", i(total), k(kind))?;
+                for mismatch in mismatches {
+                    write!(f, "\n  {}", mismatch)?;
+                }
+                if *total > mismatches.len() {
+                    write!(f, "\n  ... {} more", total - mismatches.len())?;
+                }
+                Ok(())
+            },
+            Custom(msg) => write!(f, "{}", msg),
+            CustomIndexed { msg, index } => write!(f, "{} (at index {})", msg, i(index)),
+            CustomWithSource { msg, source } => write!(f, "{}: {}", msg, Rendered { error: source, color }),
+            ElementFailed { index, source } => write!(f, "Check failed for element at index {}: {}", i(index), Rendered { error: source, color }),
+            Labeled { label, source } => match error_index(source) {
+                Some(index) => write!(f, "{} over `{}` failed at index {}.", k(&source.kind()), label, i(&index)),
+                None => write!(f, "{} over `{}` failed: {}", k(&source.kind()), label, Rendered { error: source, color }),
+            },
+            LabeledNested { outer, outer_var, inner, inner_var, source } => match source.as_ref() {
+                ForAllExistsFailed { outer_index, .. } => write!(f, "no {} in `{}` matches {} at index {} in `{}`.", inner_var, inner, outer_var, i(outer_index), outer),
+                ExistsForAllFailed { .. } => write!(f, "no {} in `{}` satisfies the predicate for every {} in `{}`.", outer_var, outer, inner_var, inner),
+                CrossPairFailed { left_index, right_index, .. } => write!(f, "predicate failed for {} at index {} in `{}` and {} at index {} in `{}`.", outer_var, i(left_index), outer, inner_var, i(right_index), inner),
+                NoMatch { .. } => write!(f, "no pair of {} in `{}` and {} in `{}` satisfies the predicate.", outer_var, outer, inner_var, inner),
+                NestedEmptyInput { side, .. } => write!(f, "`{}` is empty.", match side { Side::Left => outer, Side::Right => inner }),
+                _ => write!(f, "{} over `{}` and `{}` failed: {}", k(&source.kind()), outer, inner, Rendered { error: source, color }),
+            },
+        }
+    }
+}
+
+impl QuantorKind {
+    /// Returns the mathematical symbol of the quantifier, for notebook or markdown output.
+    ///
+    /// This complements the word-based [`Display`](fmt::Display) output,
+    /// e.g. `∀` for `forall`, `∃` for `exists`, `∄` for `none`, and `∃!` for `exactly_one`.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, error::QuantorKind};
+    ///
+    /// let err = forall(&[1, 2, 3], |x| *x < 3).unwrap_err();
+    ///
+    /// assert_eq!(err.kind().symbol(), "∀");
+    /// assert_eq!(QuantorKind::ExactlyOne.symbol(), "∃!");
+    /// assert_eq!(format!("{} {}", QuantorKind::Subset.symbol(), QuantorKind::Subset), "⊆ is_subset");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            QuantorKind::Forall => "∀",
+            QuantorKind::Exists => "∃",
+            QuantorKind::None => "∄",
+            QuantorKind::Implies => "→",
+            QuantorKind::ForallWithAggregate => "∀",
+            QuantorKind::ExactlyOne => "∃!",
+            QuantorKind::ExactlyOneOf => "⊕",
+            QuantorKind::ExactlyN => "∃ₙ",
+            QuantorKind::AtLeast => "∃≥ₙ",
+            QuantorKind::AtMost => "∃≤ₙ",
+            QuantorKind::AllEqual => "=",
+            QuantorKind::AllClose => "≈",
+            QuantorKind::AllDistinct => "≠",
+            QuantorKind::Pairwise => "∀ᵢ",
+            QuantorKind::Windowed => "∀ᵢ",
+            QuantorKind::Chunked => "∀ₖ",
+            QuantorKind::Cyclic => "∀ᵢ↻",
+            QuantorKind::StrictlyIncreasing => "<",
+            QuantorKind::Transitions => "Δ",
+            QuantorKind::Endpoints => "∂",
+            QuantorKind::SequenceEqual => "≡",
+            QuantorKind::ForallEq => "≡",
+            QuantorKind::ForAllExists => "∀∃",
+            QuantorKind::ExistsForAll => "∃∀",
+            QuantorKind::ForallForall => "∀∀",
+            QuantorKind::ExistsExists => "∃∃",
+            QuantorKind::ForallNested => "∀∀",
+            QuantorKind::Subset => "⊆",
+            QuantorKind::Superset => "⊇",
+            QuantorKind::Disjoint => "∩=∅",
+            QuantorKind::Custom => "λ",
+        }
+    }
+}
+
+impl fmt::Display for QuantorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            QuantorKind::Forall => "forall",
+            QuantorKind::Exists => "exists",
+            QuantorKind::None => "none",
+            QuantorKind::Implies => "implies",
+            QuantorKind::ForallWithAggregate => "forall_with_aggregate",
+            QuantorKind::ExactlyOne => "exactly_one",
+            QuantorKind::Pairwise => "pairwise",
+            QuantorKind::Windowed => "windowed",
+            QuantorKind::Chunked => "chunks_forall",
+            QuantorKind::Cyclic => "pairwise_cyclic",
+            QuantorKind::ExactlyN => "exactly_n",
+            QuantorKind::ExactlyOneOf => "exactly_one_of",
+            QuantorKind::AtLeast => "at_least",
+            QuantorKind::AtMost => "at_most",
+            QuantorKind::AllEqual => "all_equal",
+            QuantorKind::AllClose => "all_close",
+            QuantorKind::AllDistinct => "all_distinct",
+            QuantorKind::Transitions => "transitions_at_most",
+            QuantorKind::StrictlyIncreasing => "strictly_increasing",
+            QuantorKind::Endpoints => "endpoints",
+            QuantorKind::SequenceEqual => "matches_sequence",
+            QuantorKind::ForallEq => "forall_eq",
+            QuantorKind::ForAllExists => "forallexists",
+            QuantorKind::ExistsForAll => "existsforall",
+            QuantorKind::ForallForall => "forallforall",
+            QuantorKind::ExistsExists => "existsexists",
+            QuantorKind::ForallNested => "forall_nested",
+            QuantorKind::Subset => "is_subset",
+            QuantorKind::Superset => "is_superset",
+            QuantorKind::Disjoint => "disjoint",
+            QuantorKind::Custom => "custom",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn side(value: &Option<String>) -> String {
+            match value {
+                Some(v) => format!("\"{}\"", v),
+                None => "nothing".to_string(),
+            }
+        }
+
+        write!(f, "[{}] expected {} found {}", self.index, side(&self.expected), side(&self.found))
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Side::Left => write!(f, "left-hand"),
+            Side::Right => write!(f, "right-hand"),
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = match self {
+            Comparison::Exactly => "",
+            Comparison::AtLeast => "at least ",
+            Comparison::AtMost => "at most ",
+        };
+        write!(f, "{}", prefix)
+    }
+}
+
+impl QuantorError {
+    /// Creates a [`QuantorError::PredicateFailed`] error.
+    ///
+    /// A terse alternative to spelling out the struct variant, e.g. when writing expected errors in tests.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, error::QuantorKind, QuantorError};
+    ///
+    /// let nums = vec![1, 2, 3];
+    ///
+    /// assert_eq!(forall(&nums, |x| *x < 3), Err(QuantorError::predicate_failed(QuantorKind::Forall, 2)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn predicate_failed(kind: QuantorKind, index: usize) -> Self {
+        QuantorError::PredicateFailed { kind, index }
+    }
+
+    /// Creates a [`QuantorError::NoMatch`] error.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{exists, error::QuantorKind, QuantorError};
+    ///
+    /// let nums = vec![1, 2, 3];
+    ///
+    /// assert_eq!(exists(&nums, |x| *x > 3), Err(QuantorError::no_match(QuantorKind::Exists)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn no_match(kind: QuantorKind) -> Self {
+        QuantorError::NoMatch { kind }
+    }
+
+    /// Creates a [`QuantorError::EmptyInput`] error.
+    #[inline]
+    #[must_use]
+    pub fn empty_input(kind: QuantorKind) -> Self {
+        QuantorError::EmptyInput { kind }
+    }
+
+    /// Creates a [`QuantorError::UnexpectedMatch`] error.
+    #[inline]
+    #[must_use]
+    pub fn unexpected_match(kind: QuantorKind, index: usize) -> Self {
+        QuantorError::UnexpectedMatch { kind, index }
+    }
+
+    /// Creates a [`QuantorError::CustomIndexed`] error, for user-defined quantifiers that report a failing index.
+    ///
+    /// The error has the kind [`QuantorKind::Custom`], and its index is available via
+    /// [`QuantorResultExt::failing_index`].
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{error::{QuantorKind, QuantorResultExt}, QuantorError};
+    ///
+    /// fn all_ascii(words: &[&str]) -> Result<(), QuantorError> {
+    ///     match words.iter().position(|w| !w.is_ascii()) {
+    ///         Some(index) => Err(QuantorError::custom_at(format!("`{}` is not ASCII", words[index]), index)),
+    ///         None => Ok(()),
+    ///     }
+    /// }
+    ///
+    /// let result = all_ascii(&["tea", "café"]);
+    ///
+    /// assert_eq!(result.failing_index(), Some(1));
+    /// assert_eq!(result.kind(), Some(QuantorKind::Custom));
+    /// assert_eq!(result.unwrap_err().to_string(), "`café` is not ASCII (at index 1)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn custom_at(msg: impl Into<Cow<'static, str>>, index: usize) -> Self {
+        QuantorError::CustomIndexed { msg: msg.into(), index }
+    }
+
+    /// Returns the error with its failing index replaced by `index`.
+    ///
+    /// Only affects variants with a single `index` field, such as `PredicateFailed` or `PairwiseFailed`.
+    /// Other variants are returned unchanged. For `PredicateFailedWithContext`, the `start` of the
+    /// context window is shifted by the same amount, so the failing element stays highlighted.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{error::QuantorKind, QuantorError};
+    ///
+    /// let err = QuantorError::predicate_failed(QuantorKind::Forall, 0).with_index(4);
+    /// assert_eq!(err, QuantorError::predicate_failed(QuantorKind::Forall, 4));
+    ///
+    /// let err = QuantorError::no_match(QuantorKind::Exists).with_index(4);
+    /// assert_eq!(err, QuantorError::no_match(QuantorKind::Exists));
+    ///
+    /// let err = quantor::quantifiers::forall_context(&[1, 2, 3], |x| *x < 3, 1).unwrap_err().with_index(7);
+    /// assert!(matches!(err, QuantorError::PredicateFailedWithContext { index: 7, start: 6, .. }));
+    /// assert_eq!(err.to_string(), "Predicate failed for element at index 7 of quantifier forall; nearby: [.., 2, **3**]");
+    ///
+    /// let err = quantor::quantifiers::forall_reasoned(&[1], |_| Err("odd".into())).unwrap_err().with_index(7);
+    /// assert!(matches!(err, QuantorError::PredicateFailedReason { index: 7, .. }));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_index(mut self, index: usize) -> Self {
+        match &mut self {
+            QuantorError::PredicateFailedWithContext { index: i, start, .. } => {
+                *start = if index >= *i { start.saturating_add(index - *i) } else { start.saturating_sub(*i - index) };
+                *i = index;
+            },
+            QuantorError::PredicateFailed { index: i, .. }
+            | QuantorError::PredicatePanicked { index: i, .. }
+            | QuantorError::PredicateFailedReason { index: i, .. }
+            | QuantorError::UnexpectedMatch { index: i, .. }
+            | QuantorError::NotExactlyOneOf { index: i, .. }
+            | QuantorError::ImplicationViolated { index: i, .. }
+            | QuantorError::NotAllEqual { index: i, .. }
+            | QuantorError::PairwiseFailed { index: i, .. }
+            | QuantorError::DuplicateAt { index: i, .. }
+            | QuantorError::DecreaseAt { index: i, .. }
+            | QuantorError::TransitionLimitExceeded { index: i, .. }
+            | QuantorError::SequenceMismatch { index: i, .. }
+            | QuantorError::CustomIndexed { index: i, .. }
+            | QuantorError::ElementFailed { index: i, .. } => *i = index,
+            _ => {}
+        }
+        self
+    }
+
+    /// Returns `true` if the quantifier failed due to a predicate mismatch.
+    ///
+    /// Useful for identifying simple predicate failures, such as those from `forall` or `exactly_one`.
+    ///
+    /// ## Returns
+    /// - `true` if the error variant is [`QuantorError::PredicateFailed`].
+    /// - `false` otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, error::QuantorResultExt};
+    ///
+    /// let nums = vec![1, 2, 3];
+    /// let result = forall(&nums, |x| *x < 3);
+    ///
+    /// assert!(result.is_err());
+    /// assert!(result.unwrap_err().is_predicate_failed());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_predicate_failed(&self) -> bool {
+        matches!(self, QuantorError::PredicateFailed {..})
+    }
+
+    /// Returns `true` if the quantifier failed because no element matched the predicate.
+    ///
+    /// Typically used with `exists` or `existsforall` where at least one match is expected.
+    ///
+    /// ## Returns
+    /// - `true` if the error variant is [`QuantorError::NoMatch`].
+    /// - `false` otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{exists, error::QuantorResultExt};
+    ///
+    /// let nums = [1, 2, 3];
+    /// let result = exists(&nums, |x| *x > 10);
+    ///
+    /// assert!(result.is_err());
+    /// assert!(result.unwrap_err().is_no_match());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_no_match(&self) -> bool {
+        matches!(self, QuantorError::NoMatch { .. })
+    }
+
+    /// Returns `true` if the quantifier failed because it was given no elements.
+    ///
+    /// Useful for distinguishing "validation failed" from "there was nothing to validate".
+    ///
+    /// ## Returns
+    /// - `true` if the error variant is [`QuantorError::EmptyInput`] or [`QuantorError::NestedEmptyInput`].
+    /// - `false` otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::exactly_one;
+    ///
+    /// let empty: Vec<i32> = vec![];
+    /// let result = exactly_one(&empty, |x| *x > 0);
+    ///
+    /// assert!(result.unwrap_err().is_empty_input());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty_input(&self) -> bool {
+        matches!(self, QuantorError::EmptyInput { .. } | QuantorError::NestedEmptyInput { .. })
+    }
+
+    /// Returns `true` if the quantifier failed because an element matched unexpectedly.
+    ///
+    /// Typically produced by `none` or `exactly_one`.
+    ///
+    /// ## Returns
+    /// - `true` if the error variant is [`QuantorError::UnexpectedMatch`].
+    /// - `false` otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::none;
+    ///
+    /// let nums = [1, 2, 3];
+    /// let result = none(&nums, |x| *x == 2);
+    ///
+    /// assert!(result.unwrap_err().is_unexpected_match());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_unexpected_match(&self) -> bool {
+        matches!(self, QuantorError::UnexpectedMatch { .. })
+    }
+
+    /// Returns `true` if the quantifier failed because not all elements were equal.
+    ///
+    /// Typically produced by `all_equal`.
+    ///
+    /// ## Returns
+    /// - `true` if the error variant is [`QuantorError::NotAllEqual`].
+    /// - `false` otherwise.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::all_equal;
+    ///
+    /// let nums = [1, 1, 2];
+    /// let result = all_equal(&nums);
+    ///
+    /// assert!(result.unwrap_err().is_not_all_equal());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_not_all_equal(&self) -> bool {
+        matches!(self, QuantorError::NotAllEqual { .. })
+    }
+
+    /// Returns a stable, machine-readable code identifying the error variant.
+    ///
+    /// Codes do not change between releases, so logs and downstream systems can route on them
+    /// instead of parsing the [`Display`](fmt::Display) message. Wrapper variants report their
+    /// own code; use [`std::error::Error::source`] to reach the wrapped error.
+    ///
+    /// | Variant | Code |
+    /// |---------|------|
+    /// | `PredicateFailed` | `QE_PREDICATE_FAILED` |
+    /// | `PredicateFailedWithContext` | `QE_PREDICATE_FAILED_WITH_CONTEXT` |
+    /// | `PredicatePanicked` | `QE_PREDICATE_PANICKED` |
+    /// | `PredicateFailedReason` | `QE_PREDICATE_FAILED_REASON` |
+    /// | `EmptyInput` | `QE_EMPTY_INPUT` |
+    /// | `NestedEmptyInput` | `QE_NESTED_EMPTY_INPUT` |
+    /// | `NoMatch` | `QE_NO_MATCH` |
+    /// | `UnexpectedMatch` | `QE_UNEXPECTED_MATCH` |
+    /// | `NotExactlyOneOf` | `QE_NOT_EXACTLY_ONE_OF` |
+    /// | `ImplicationViolated` | `QE_IMPLICATION_VIOLATED` |
+    /// | `AggregateFailed` | `QE_AGGREGATE_FAILED` |
+    /// | `NotAllEqual` | `QE_NOT_ALL_EQUAL` |
+    /// | `PairwiseFailed` | `QE_PAIRWISE_FAILED` |
+    /// | `DuplicateAt` | `QE_DUPLICATE_AT` |
+    /// | `DecreaseAt` | `QE_DECREASE_AT` |
+    /// | `ForAllExistsFailed` | `QE_FORALL_EXISTS_FAILED` |
+    /// | `ExistsForAllFailed` | `QE_EXISTS_FORALL_FAILED` |
+    /// | `CrossPairFailed` | `QE_CROSS_PAIR_FAILED` |
+    /// | `NestedPredicateFailed` | `QE_NESTED_PREDICATE_FAILED` |
+    /// | `ExactlyNFailed` | `QE_EXACTLY_N_FAILED` |
+    /// | `TransitionLimitExceeded` | `QE_TRANSITION_LIMIT_EXCEEDED` |
+    /// | `SequenceMismatch` | `QE_SEQUENCE_MISMATCH` |
+    /// | `LengthMismatch` | `QE_LENGTH_MISMATCH` |
+    /// | `ElementsMismatched` | `QE_ELEMENTS_MISMATCHED` |
+    /// | `Custom` | `QE_CUSTOM` |
+    /// | `CustomIndexed` | `QE_CUSTOM_INDEXED` |
+    /// | `CustomWithSource` | `QE_CUSTOM_WITH_SOURCE` |
+    /// | `ElementFailed` | `QE_ELEMENT_FAILED` |
+    /// | `Labeled` | `QE_LABELED` |
+    /// | `LabeledNested` | `QE_LABELED_NESTED` |
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, exists};
+    ///
+    /// let nums = [1, 2, 3];
+    ///
+    /// assert_eq!(forall(&nums, |x| *x > 1).unwrap_err().code(), "QE_PREDICATE_FAILED");
+    /// assert_eq!(exists(&nums, |x| *x > 3).unwrap_err().code(), "QE_NO_MATCH");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            QuantorError::PredicateFailed { .. } => "QE_PREDICATE_FAILED",
+            QuantorError::PredicateFailedWithContext { .. } => "QE_PREDICATE_FAILED_WITH_CONTEXT",
+            QuantorError::PredicatePanicked { .. } => "QE_PREDICATE_PANICKED",
+            QuantorError::PredicateFailedReason { .. } => "QE_PREDICATE_FAILED_REASON",
+            QuantorError::EmptyInput { .. } => "QE_EMPTY_INPUT",
+            QuantorError::NestedEmptyInput { .. } => "QE_NESTED_EMPTY_INPUT",
+            QuantorError::NoMatch { .. } => "QE_NO_MATCH",
+            QuantorError::UnexpectedMatch { .. } => "QE_UNEXPECTED_MATCH",
+            QuantorError::NotExactlyOneOf { .. } => "QE_NOT_EXACTLY_ONE_OF",
+            QuantorError::ImplicationViolated { .. } => "QE_IMPLICATION_VIOLATED",
+            QuantorError::AggregateFailed { .. } => "QE_AGGREGATE_FAILED",
+            QuantorError::NotAllEqual { .. } => "QE_NOT_ALL_EQUAL",
+            QuantorError::PairwiseFailed { .. } => "QE_PAIRWISE_FAILED",
+            QuantorError::DuplicateAt { .. } => "QE_DUPLICATE_AT",
+            QuantorError::DecreaseAt { .. } => "QE_DECREASE_AT",
+            QuantorError::ForAllExistsFailed { .. } => "QE_FORALL_EXISTS_FAILED",
+            QuantorError::ExistsForAllFailed { .. } => "QE_EXISTS_FORALL_FAILED",
+            QuantorError::CrossPairFailed { .. } => "QE_CROSS_PAIR_FAILED",
+            QuantorError::NestedPredicateFailed { .. } => "QE_NESTED_PREDICATE_FAILED",
+            QuantorError::ExactlyNFailed { .. } => "QE_EXACTLY_N_FAILED",
+            QuantorError::TransitionLimitExceeded { .. } => "QE_TRANSITION_LIMIT_EXCEEDED",
+            QuantorError::SequenceMismatch { .. } => "QE_SEQUENCE_MISMATCH",
+            QuantorError::LengthMismatch { .. } => "QE_LENGTH_MISMATCH",
+            QuantorError::ElementsMismatched { .. } => "QE_ELEMENTS_MISMATCHED",
+            QuantorError::Custom(_) => "QE_CUSTOM",
+            QuantorError::CustomIndexed { .. } => "QE_CUSTOM_INDEXED",
+            QuantorError::CustomWithSource { .. } => "QE_CUSTOM_WITH_SOURCE",
+            QuantorError::ElementFailed { .. } => "QE_ELEMENT_FAILED",
+            QuantorError::Labeled { .. } => "QE_LABELED",
+            QuantorError::LabeledNested { .. } => "QE_LABELED_NESTED",
+        }
+    }
+
+    /// Returns the [`QuantorKind`] associated with this error.
+    ///
+    /// Allows inspection of which quantifier failed, regardless of the specific error variant.
+    ///
+    /// ## Returns
+    /// - A [`QuantorKind`] value corresponding to the quantifier that produced the error.
+    /// - [`QuantorKind::Custom`] for generic errors.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, error::{QuantorKind, QuantorResultExt}};
+    ///
+    /// let nums = [1, 2, 3];
+    /// let result = forall(&nums, |x| *x > 3);
+    ///
+    /// assert_eq!(result.unwrap_err().kind(), QuantorKind::Forall);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn kind(&self) -> QuantorKind {
+        match self {
+            QuantorError::PredicateFailed { kind, .. } => *kind,
+            QuantorError::PredicateFailedWithContext { kind, .. } => *kind,
+            QuantorError::PredicatePanicked { kind, .. } => *kind,
+            QuantorError::PredicateFailedReason { kind, .. } => *kind,
+            QuantorError::EmptyInput { kind } => *kind,
+            QuantorError::NestedEmptyInput { kind, .. } => *kind,
+            QuantorError::NoMatch { kind, .. } => *kind,
+            QuantorError::UnexpectedMatch { kind, .. } => *kind,
+            QuantorError::NotExactlyOneOf { kind, .. } => *kind,
+            QuantorError::ImplicationViolated { kind, .. } => *kind,
+            QuantorError::AggregateFailed { kind, .. } => *kind,
+            QuantorError::NotAllEqual { kind, .. } => *kind,
+            QuantorError::PairwiseFailed { kind, .. } => *kind,
+            QuantorError::DuplicateAt { kind, .. } => *kind,
+            QuantorError::DecreaseAt { kind, .. } => *kind,
+            QuantorError::ForAllExistsFailed { kind, .. } => *kind,
+            QuantorError::ExistsForAllFailed { kind, .. } => *kind,
+            QuantorError::CrossPairFailed { kind, .. } => *kind,
+            QuantorError::NestedPredicateFailed { kind, .. } => *kind,
+            QuantorError::ExactlyNFailed { kind, .. } => *kind,
+            QuantorError::TransitionLimitExceeded { kind, .. } => *kind,
+            QuantorError::SequenceMismatch { kind, .. } => *kind,
+            QuantorError::LengthMismatch { kind, .. } => *kind,
+            QuantorError::ElementsMismatched { kind, .. } => *kind,
+            QuantorError::Custom(_) => QuantorKind::Custom,
+            QuantorError::CustomIndexed { .. } => QuantorKind::Custom,
+            QuantorError::CustomWithSource { source, .. } => source.kind(),
+            QuantorError::ElementFailed { source, .. } => source.kind(),
+            QuantorError::Labeled { source, .. } => source.kind(),
+            QuantorError::LabeledNested { source, .. } => source.kind(),
+        }
+    }
+
+    /// Combines this error with another one into a [`QuantorErrors`] collection.
+    ///
+    /// Useful for accumulating failures of independent checks in manual validation loops.
+    ///
+    /// ## Returns
+    /// - A [`QuantorErrors`] containing `self` followed by `other`.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, exists, none};
+    ///
+    /// let nums = [1, 2, 3];
+    ///
+    /// let a = forall(&nums, |x| *x > 1).unwrap_err();
+    /// let b = exists(&nums, |x| *x > 5).unwrap_err();
+    /// let c = none(&nums, |x| *x == 3).unwrap_err();
+    ///
+    /// let mut errors = a.merge(b);
+    /// errors.push(c);
+    ///
+    /// assert_eq!(errors.len(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn merge(self, other: QuantorError) -> QuantorErrors {
+        QuantorErrors { errors: vec![self, other], display_limit: None }
+    }
+
+    /// Renders the error message with ANSI colors for terminal output.
+    ///
+    /// Quantifier names are shown in bold cyan, indices and counts in yellow. The message text is
+    /// otherwise identical to the [`Display`](fmt::Display) output, which always stays plain.
+    ///
+    /// Requires the `color` feature.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::forall;
+    ///
+    /// let err = forall(&[1, 2, 3], |x| *x < 2).unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     err.colored(),
+    ///     "Predicate failed for element at index \x1b[33m1\x1b[0m of quantifier \x1b[1;36mforall\x1b[0m."
+    /// );
+    /// assert_eq!(err.to_string(), "Predicate failed for element at index 1 of quantifier forall.");
+    /// ```
+    #[cfg(feature = "color")]
+    #[must_use]
+    pub fn colored(&self) -> String {
+        Rendered { error: self, color: true }.to_string()
+    }
+
+    /// Renders the error message through a custom [`MessageFormatter`].
+    ///
+    /// Use this instead of `to_string()` to localize or reword messages without reimplementing
+    /// [`Display`](fmt::Display), which keeps producing the default English text.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{exists, QuantorError};
+    ///
+    /// let err = exists(&[1, 2, 3], |x| *x > 5).unwrap_err();
+    ///
+    /// let short = |e: &QuantorError| format!("{} failed ({})", e.kind(), e.code());
+    ///
+    /// assert_eq!(err.message(&short), "exists failed (QE_NO_MATCH)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn message(&self, formatter: &dyn MessageFormatter) -> String {
+        formatter.format(self)
+    }
+}
+
+/// The default maximum number of errors listed when displaying a [`QuantorErrors`].
+///
+/// Override it per collection with [`QuantorErrors::with_display_limit`], or per call with
+/// [`QuantorErrors::display_limited`].
+pub const ERRORS_DISPLAY_LIMIT: usize = 10;
+
+/// A collection of quantifier failures, kept in the order they were recorded.
+///
+/// Returned by checks that report every failure instead of stopping at the first one,
+/// and built manually via [`QuantorError::merge`] and [`QuantorErrors::push`].
+///
+/// Displays as a multi-line list of its errors, truncated after [`ERRORS_DISPLAY_LIMIT`] entries
+/// by default. The remaining errors are summarized as `"... N more"`.
+///
+/// Equality compares the errors only, not the display limit.
+///
+/// ## Ordering
+/// Errors are always displayed in insertion order, so the output is deterministic for a
+/// deterministic sequence of checks and can be used in snapshot tests. When errors are gathered
+/// in an order that is not stable, e.g. from several threads, call [`QuantorErrors::sort_by_index`]
+/// before displaying them.
+///
+/// ## Example
+/// ```
+/// use quantor::{forall, exists, none, combine::all_of};
+///
+/// let nums = vec![1, 2, 3];
+///
+/// let errors = all_of([
+///     forall(&nums, |x| *x < 3),
+///     exists(&nums, |x| *x > 5),
+///     none(&nums, |x| *x == 2),
+/// ]).unwrap_err();
+///
+/// assert_eq!(errors.to_string(), "\
+/// 3 quantifier check(s) failed:
+///   - Predicate failed for element at index 2 of quantifier forall.
+///   - No element satisfied the predicate for quantifier exists.
+///   - Unexpected match found at index 1 of quantifier none.");
+/// ```
+#[derive(Default)]
+pub struct QuantorErrors {
+    errors: Vec<QuantorError>,
+    /// The number of errors listed by `Display`, or `None` for [`ERRORS_DISPLAY_LIMIT`].
+    display_limit: Option<usize>,
+}
+
+impl QuantorErrors {
+    /// Creates an empty collection.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an error to the collection.
+    #[inline]
+    pub fn push(&mut self, error: QuantorError) {
+        self.errors.push(error);
+    }
+
+    /// Returns the number of errors in the collection.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if the collection contains no errors.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns an iterator over the errors, in the order they were recorded.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, QuantorError> {
+        self.errors.iter()
+    }
+
+    /// Sorts the errors by their failing index, in ascending order.
+    ///
+    /// The sort is stable: errors with the same index keep their relative order, and errors
+    /// without an index (such as `NoMatch`) are moved to the end.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{forall, exists, none, QuantorErrors};
+    ///
+    /// let nums = [1, 2, 3];
+    ///
+    /// let mut errors: QuantorErrors = [
+    ///     exists(&nums, |x| *x > 5).unwrap_err(),
+    ///     forall(&nums, |x| *x < 3).unwrap_err(),
+    ///     none(&nums, |x| *x == 1).unwrap_err(),
+    /// ].into_iter().collect();
+    ///
+    /// errors.sort_by_index();
+    ///
+    /// assert_eq!(errors.to_string(), "\
+    /// 3 quantifier check(s) failed:
+    ///   - Unexpected match found at index 0 of quantifier none.
+    ///   - Predicate failed for element at index 2 of quantifier forall.
+    ///   - No element satisfied the predicate for quantifier exists.");
+    /// ```
+    #[inline]
+    pub fn sort_by_index(&mut self) {
+        self.errors.sort_by_key(|e| {
+            let index = error_index(e);
+            (index.is_none(), index)
+        });
+    }
+
+    /// Sets the number of errors listed when the collection is displayed.
+    ///
+    /// Errors beyond the limit are summarized as `"... N more"`. Pass `usize::MAX` to list every
+    /// error. The default is [`ERRORS_DISPLAY_LIMIT`].
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::quantifiers::forall_collect;
+    ///
+    /// let nums = vec![1, 2, 3, 4, 5];
+    /// let errors = forall_collect(&nums, |x| *x > 5).unwrap_err();
+    ///
+    /// assert_eq!(errors.with_display_limit(2).to_string(), "\
+    /// 5 quantifier check(s) failed:
+    ///   - Predicate failed for element at index 0 of quantifier forall.
+    ///   - Predicate failed for element at index 1 of quantifier forall.
+    ///   ... 3 more");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_display_limit(mut self, limit: usize) -> Self {
+        self.display_limit = Some(limit);
+        self
+    }
+
+    /// Displays the collection listing at most `limit` errors, regardless of its own display limit.
+    ///
+    /// Useful when the same errors are written to several sinks, e.g. all of them to a CI log
+    /// and only a few to a terminal. Pass `usize::MAX` to list every error.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::quantifiers::forall_collect;
+    ///
+    /// let nums: Vec<u32> = (0..12).collect();
+    /// let errors = forall_collect(&nums, |x| *x > 20).unwrap_err();
+    ///
+    /// assert!(errors.to_string().ends_with("... 2 more"));
+    /// assert_eq!(errors.display_limited(usize::MAX).to_string().lines().count(), 13);
+    ///
+    /// let short = errors.display_limited(1).to_string();
+    /// assert!(short.ends_with("index 0 of quantifier forall.\n  ... 11 more"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn display_limited(&self, limit: usize) -> DisplayLimited<'_> {
+        DisplayLimited { errors: self, limit }
+    }
+
+    /// Converts the collection into a result.
+    ///
+    /// ## Returns
+    /// - `Ok(())` if the collection is empty.
+    /// - `Err(self)` otherwise.
+    #[inline]
+    pub fn into_result(self) -> Result<(), QuantorErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl QuantorErrors {
+    /// Writes the error list, truncated after `limit` entries.
+    fn render(&self, f: &mut fmt::Formatter<'_>, limit: usize) -> fmt::Result {
+        write!(f, "{} quantifier check(s) failed:", self.errors.len())?;
+        for error in self.errors.iter().take(limit) {
+            write!(f, "\n  - {}", error)?;
+        }
+        if self.errors.len() > limit {
+            write!(f, "\n  ... {} more", self.errors.len() - limit)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for QuantorErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, self.display_limit.unwrap_or(ERRORS_DISPLAY_LIMIT))
+    }
+}
+
+impl PartialEq for QuantorErrors {
+    fn eq(&self, other: &Self) -> bool {
+        self.errors == other.errors
+    }
+}
+
+impl Eq for QuantorErrors {}
+
+/// Displays a [`QuantorErrors`] with a custom number of listed errors.
+///
+/// Returned by [`QuantorErrors::display_limited`].
+pub struct DisplayLimited<'a> {
+    errors: &'a QuantorErrors,
+    limit: usize,
+}
+
+impl fmt::Display for DisplayLimited<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.errors.render(f, self.limit)
+    }
+}
+
+impl fmt::Debug for QuantorErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for QuantorErrors {}
+
+impl From<QuantorError> for QuantorErrors {
+    fn from(error: QuantorError) -> Self {
+        QuantorErrors { errors: vec![error], display_limit: None }
+    }
+}
+
+impl FromIterator<QuantorError> for QuantorErrors {
+    fn from_iter<I: IntoIterator<Item = QuantorError>>(iter: I) -> Self {
+        QuantorErrors { errors: iter.into_iter().collect(), display_limit: None }
+    }
+}
+
+impl Extend<QuantorError> for QuantorErrors {
+    fn extend<I: IntoIterator<Item = QuantorError>>(&mut self, iter: I) {
+        self.errors.extend(iter);
+    }
+}
+
+impl IntoIterator for QuantorErrors {
+    type Item = QuantorError;
+    type IntoIter = std::vec::IntoIter<QuantorError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a QuantorErrors {
+    type Item = &'a QuantorError;
+    type IntoIter = std::slice::Iter<'a, QuantorError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+
+impl std::error::Error for QuantorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Err(QuantorError::ExactlyNFailed { found, .. }) => Some(*found),
-            _ => None
+            QuantorError::CustomWithSource { source, .. } => Some(source.as_ref()),
+            QuantorError::ElementFailed { source, .. } => Some(source.as_ref()),
+            QuantorError::Labeled { source, .. } => Some(source.as_ref()),
+            QuantorError::LabeledNested { source, .. } => Some(source.as_ref()),
+            _ => None,
         }
     }
+}
+
+/// Renders quantifier errors as [`miette`](https://docs.rs/miette) diagnostics.
+///
+/// The diagnostic code is derived from the quantifier kind (e.g. `quantor::forall`), and errors
+/// carrying an index get a help message pointing at the failing element.
+///
+/// Requires the `miette` feature.
+///
+/// ## Example
+/// ```
+/// use miette::Diagnostic;
+/// use quantor::forall;
+///
+/// let err = forall(&[1, 2, 3], |x| *x < 3).unwrap_err();
+///
+/// // `Diagnostic::code` is shadowed by the inherent `QuantorError::code`.
+/// assert_eq!(Diagnostic::code(&err).unwrap().to_string(), "quantor::forall");
+/// assert_eq!(err.help().unwrap().to_string(), "the first failing element is at index 2");
+/// assert_eq!(err.code(), "QE_PREDICATE_FAILED");
+/// ```
+#[cfg(feature = "miette")]
+impl miette::Diagnostic for QuantorError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(format!("quantor::{}", self.kind())))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        error_index(self).map(|index| Box::new(format!("the first failing element is at index {}", index)) as Box<dyn fmt::Display>)
+    }
+}
+
+impl From<&'static str> for QuantorError {
+    fn from(msg: &'static str) -> Self {
+        QuantorError::Custom(Cow::Borrowed(msg))
+    }
+}
+
+impl From<String> for QuantorError {
+    fn from(msg: String) -> Self {
+        QuantorError::Custom(Cow::Owned(msg))
+    }
+}
+
+impl fmt::Debug for QuantorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render(f, false)
+    }
+}
+
+impl PartialOrd for QuantorError {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QuantorError {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let index_key = |e: &QuantorError| {
+            let index = error_index(e);
+            (index.is_none(), index)
+        };
+
+        self.kind().cmp(&other.kind())
+            .then_with(|| index_key(self).cmp(&index_key(other)))
+            .then_with(|| self.code().cmp(other.code()))
+            .then_with(|| fields_cmp(self, other))
+    }
+}
+
+/// Compares the fields of two errors of the same variant, in declaration order.
+///
+/// Errors of different variants are told apart by their code. The match on `a` is exhaustive, so
+/// every new variant must define how its fields compare.
+fn fields_cmp(a: &QuantorError, b: &QuantorError) -> Ordering {
+    use QuantorError::*;
+
+    a.code().cmp(b.code()).then_with(|| match a {
+        PredicateFailed { kind: k1, index: i1 } => {
+            let PredicateFailed { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        PredicateFailedWithContext { kind: k1, index: i1, start: s1, nearby: n1, len: l1 } => {
+            let PredicateFailedWithContext { kind: k2, index: i2, start: s2, nearby: n2, len: l2 } = b else { unreachable!() };
+            (k1, i1, s1, n1, l1).cmp(&(k2, i2, s2, n2, l2))
+        },
+        PredicatePanicked { kind: k1, index: i1, message: m1 } => {
+            let PredicatePanicked { kind: k2, index: i2, message: m2 } = b else { unreachable!() };
+            (k1, i1, m1).cmp(&(k2, i2, m2))
+        },
+        PredicateFailedReason { kind: k1, index: i1, reason: r1 } => {
+            let PredicateFailedReason { kind: k2, index: i2, reason: r2 } = b else { unreachable!() };
+            (k1, i1, r1).cmp(&(k2, i2, r2))
+        },
+        EmptyInput { kind: k1 } => {
+            let EmptyInput { kind: k2 } = b else { unreachable!() };
+            k1.cmp(k2)
+        },
+        NestedEmptyInput { kind: k1, side: s1 } => {
+            let NestedEmptyInput { kind: k2, side: s2 } = b else { unreachable!() };
+            (k1, s1).cmp(&(k2, s2))
+        },
+        NoMatch { kind: k1 } => {
+            let NoMatch { kind: k2 } = b else { unreachable!() };
+            k1.cmp(k2)
+        },
+        UnexpectedMatch { kind: k1, index: i1 } => {
+            let UnexpectedMatch { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        NotExactlyOneOf { kind: k1, index: i1, matched: m1 } => {
+            let NotExactlyOneOf { kind: k2, index: i2, matched: m2 } = b else { unreachable!() };
+            (k1, i1, m1).cmp(&(k2, i2, m2))
+        },
+        ImplicationViolated { kind: k1, index: i1 } => {
+            let ImplicationViolated { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        AggregateFailed { kind: k1, len: l1 } => {
+            let AggregateFailed { kind: k2, len: l2 } = b else { unreachable!() };
+            (k1, l1).cmp(&(k2, l2))
+        },
+        NotAllEqual { kind: k1, index: i1 } => {
+            let NotAllEqual { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        PairwiseFailed { kind: k1, index: i1 } => {
+            let PairwiseFailed { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        DuplicateAt { kind: k1, index: i1 } => {
+            let DuplicateAt { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        DecreaseAt { kind: k1, index: i1 } => {
+            let DecreaseAt { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        ForAllExistsFailed { kind: k1, outer_index: o1 } => {
+            let ForAllExistsFailed { kind: k2, outer_index: o2 } = b else { unreachable!() };
+            (k1, o1).cmp(&(k2, o2))
+        },
+        ExistsForAllFailed { kind: k1, outer_index: o1 } => {
+            let ExistsForAllFailed { kind: k2, outer_index: o2 } = b else { unreachable!() };
+            (k1, o1).cmp(&(k2, o2))
+        },
+        CrossPairFailed { kind: k1, left_index: l1, right_index: r1 } => {
+            let CrossPairFailed { kind: k2, left_index: l2, right_index: r2 } = b else { unreachable!() };
+            (k1, l1, r1).cmp(&(k2, l2, r2))
+        },
+        NestedPredicateFailed { kind: k1, outer_index: o1, inner_index: n1 } => {
+            let NestedPredicateFailed { kind: k2, outer_index: o2, inner_index: n2 } = b else { unreachable!() };
+            (k1, o1, n1).cmp(&(k2, o2, n2))
+        },
+        ExactlyNFailed { kind: k1, found: f1, expected: e1, comparison: c1 } => {
+            let ExactlyNFailed { kind: k2, found: f2, expected: e2, comparison: c2 } = b else { unreachable!() };
+            (k1, f1, e1, c1).cmp(&(k2, f2, e2, c2))
+        },
+        TransitionLimitExceeded { kind: k1, index: i1, limit: l1 } => {
+            let TransitionLimitExceeded { kind: k2, index: i2, limit: l2 } = b else { unreachable!() };
+            (k1, i1, l1).cmp(&(k2, i2, l2))
+        },
+        SequenceMismatch { kind: k1, index: i1 } => {
+            let SequenceMismatch { kind: k2, index: i2 } = b else { unreachable!() };
+            (k1, i1).cmp(&(k2, i2))
+        },
+        LengthMismatch { kind: k1, found: f1, expected: e1 } => {
+            let LengthMismatch { kind: k2, found: f2, expected: e2 } = b else { unreachable!() };
+            (k1, f1, e1).cmp(&(k2, f2, e2))
+        },
+        ElementsMismatched { kind: k1, mismatches: m1, total: t1 } => {
+            let ElementsMismatched { kind: k2, mismatches: m2, total: t2 } = b else { unreachable!() };
+            (k1, m1, t1).cmp(&(k2, m2, t2))
+        },
+        Custom(m1) => {
+            let Custom(m2) = b else { unreachable!() };
+            m1.cmp(m2)
+        },
+        CustomIndexed { msg: m1, index: i1 } => {
+            let CustomIndexed { msg: m2, index: i2 } = b else { unreachable!() };
+            (m1, i1).cmp(&(m2, i2))
+        },
+        CustomWithSource { msg: m1, source: s1 } => {
+            let CustomWithSource { msg: m2, source: s2 } = b else { unreachable!() };
+            (m1, s1).cmp(&(m2, s2))
+        },
+        ElementFailed { index: i1, source: s1 } => {
+            let ElementFailed { index: i2, source: s2 } = b else { unreachable!() };
+            (i1, s1).cmp(&(i2, s2))
+        },
+        Labeled { label: l1, source: s1 } => {
+            let Labeled { label: l2, source: s2 } = b else { unreachable!() };
+            (l1, s1).cmp(&(l2, s2))
+        },
+        LabeledNested { outer: o1, outer_var: ov1, inner: n1, inner_var: nv1, source: s1 } => {
+            let LabeledNested { outer: o2, outer_var: ov2, inner: n2, inner_var: nv2, source: s2 } = b else { unreachable!() };
+            (o1, ov1, n1, nv1, s1).cmp(&(o2, ov2, n2, nv2, s2))
+        },
+    })
+}
+
+impl<T> QuantorResultExt for Result<T, QuantorError> {
+    #[inline]
+    fn failing_index(&self) -> Option<usize> {
+        self.as_ref().err().and_then(error_index)
+    }
+
+    #[inline]
+    fn failing_indices(&self) -> Vec<usize> {
+        self.as_ref().err().map(error_indices).unwrap_or_default()
+    }
+
+    #[inline]
+    fn passed_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(error_passed_count)
+    }
+
+    #[inline]
+    fn match_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(error_match_count)
+    }
+
+    #[inline]
+    fn expected_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(error_expected_count)
+    }
+
+    #[inline]
+    fn kind(&self) -> Option<QuantorKind> {
+        self.as_ref().err().map(QuantorError::kind)
+    }
+
+    #[inline]
+    fn context(self, msg: &'static str) -> Self {
+        self.map_err(|e| QuantorError::CustomWithSource { msg, source: Box::new(e) })
+    }
+
+    #[inline]
+    fn and_also<F>(self, next: F) -> Self
+    where F: FnOnce() -> Self {
+        self.and_then(|_| next())
+    }
+}
+
+impl<T> QuantorResultExt for Result<T, QuantorErrors> {
+    #[inline]
+    fn failing_index(&self) -> Option<usize> {
+        self.as_ref().err().and_then(|errors| errors.iter().find_map(error_index))
+    }
+
+    #[inline]
+    fn failing_indices(&self) -> Vec<usize> {
+        self.as_ref().err().map(|errors| errors.iter().flat_map(error_indices).collect()).unwrap_or_default()
+    }
+
+    #[inline]
+    fn passed_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(|errors| errors.iter().find_map(error_passed_count))
+    }
+
+    #[inline]
+    fn match_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(|errors| errors.iter().find_map(error_match_count))
+    }
+
+    #[inline]
+    fn expected_count(&self) -> Option<usize> {
+        self.as_ref().err().and_then(|errors| errors.iter().find_map(error_expected_count))
+    }
+
+    #[inline]
+    fn kind(&self) -> Option<QuantorKind> {
+        self.as_ref().err().and_then(|errors| errors.iter().next()).map(QuantorError::kind)
+    }
+
+    #[inline]
+    fn context(self, msg: &'static str) -> Self {
+        self.map_err(|errors| errors.into_iter().map(|e| QuantorError::CustomWithSource { msg, source: Box::new(e) }).collect())
+    }
+
+    #[inline]
+    fn and_also<F>(self, next: F) -> Self
+    where F: FnOnce() -> Self {
+        self.and_then(|_| next())
+    }
+}
+
+fn error_indices(err: &QuantorError) -> Vec<usize> {
+    match err {
+        QuantorError::ElementsMismatched { mismatches, .. } => mismatches.iter().map(|m| m.index).collect(),
+        QuantorError::CustomWithSource { source, .. }
+        | QuantorError::Labeled { source, .. }
+        | QuantorError::LabeledNested { source, .. } => error_indices(source),
+        _ => error_index(err).into_iter().collect(),
+    }
+}
+
+pub(crate) fn error_index(err: &QuantorError) -> Option<usize> {
+    match err {
+        QuantorError::PredicateFailed { index, .. } => Some(*index),
+        QuantorError::PredicateFailedWithContext { index, .. } => Some(*index),
+        QuantorError::PredicatePanicked { index, .. } => Some(*index),
+        QuantorError::PredicateFailedReason { index, .. } => Some(*index),
+        QuantorError::UnexpectedMatch { index, .. } => Some(*index),
+        QuantorError::NotExactlyOneOf { index, .. } => Some(*index),
+        QuantorError::ImplicationViolated { index, .. } => Some(*index),
+        QuantorError::NotAllEqual { index, .. } => Some(*index),
+        QuantorError::PairwiseFailed { index, .. } => Some(*index),
+        QuantorError::DuplicateAt { index, .. } => Some(*index),
+        QuantorError::DecreaseAt { index, .. } => Some(*index),
+        QuantorError::ForAllExistsFailed { outer_index, .. } => Some(*outer_index),
+        QuantorError::ExistsForAllFailed { outer_index, .. } => Some(*outer_index),
+        QuantorError::CrossPairFailed { left_index, .. } => Some(*left_index),
+        QuantorError::NestedPredicateFailed { outer_index, .. } => Some(*outer_index),
+        QuantorError::TransitionLimitExceeded { index, .. } => Some(*index),
+        QuantorError::SequenceMismatch { index, .. } => Some(*index),
+        QuantorError::CustomIndexed { index, .. } => Some(*index),
+        QuantorError::ElementsMismatched { mismatches, .. } => mismatches.first().map(|m| m.index),
+        QuantorError::CustomWithSource { source, .. } => error_index(source),
+        QuantorError::ElementFailed { index, .. } => Some(*index),
+        QuantorError::Labeled { source, .. } => error_index(source),
+        QuantorError::LabeledNested { source, .. } => error_index(source),
+        _ => None,
+    }
+}
+
+fn error_passed_count(err: &QuantorError) -> Option<usize> {
+    // Only quantifiers that evaluate front to back and stop at the first failing element.
+    // `exactly_one`, `all_distinct` or `disjoint` report other positions under the same variants.
+    let element_wise = |kind: &QuantorKind| matches!(
+        kind,
+        QuantorKind::Forall | QuantorKind::None | QuantorKind::Implies | QuantorKind::ExactlyOneOf | QuantorKind::ForallWithAggregate
+    );
+
+    match err {
+        QuantorError::PredicateFailed { kind, index }
+        | QuantorError::PredicateFailedWithContext { kind, index, .. }
+        | QuantorError::PredicatePanicked { kind, index, .. }
+        | QuantorError::PredicateFailedReason { kind, index, .. }
+        | QuantorError::UnexpectedMatch { kind, index }
+        | QuantorError::ImplicationViolated { kind, index }
+        | QuantorError::NotExactlyOneOf { kind, index, .. } if element_wise(kind) => Some(*index),
+        QuantorError::ElementFailed { index, .. } => Some(*index),
+        QuantorError::CustomWithSource { source, .. }
+        | QuantorError::Labeled { source, .. }
+        | QuantorError::LabeledNested { source, .. } => error_passed_count(source),
+        _ => None,
+    }
+}
+
+fn error_expected_count(err: &QuantorError) -> Option<usize> {
+    match err {
+        QuantorError::ExactlyNFailed { expected, .. } => Some(*expected),
+        QuantorError::NotExactlyOneOf { .. } => Some(1),
+        QuantorError::CustomWithSource { source, .. } => error_expected_count(source),
+        QuantorError::ElementFailed { source, .. } => error_expected_count(source),
+        QuantorError::Labeled { source, .. } => error_expected_count(source),
+        QuantorError::LabeledNested { source, .. } => error_expected_count(source),
+        _ => None
+    }
+}
+
+fn error_match_count(err: &QuantorError) -> Option<usize> {
+    match err {
+        QuantorError::ExactlyNFailed { found, .. } => Some(*found),
+        QuantorError::NotExactlyOneOf { matched, .. } => Some(*matched),
+        QuantorError::CustomWithSource { source, .. } => error_match_count(source),
+        QuantorError::ElementFailed { source, .. } => error_match_count(source),
+        QuantorError::Labeled { source, .. } => error_match_count(source),
+        QuantorError::LabeledNested { source, .. } => error_match_count(source),
+        _ => None
+    }
 }
\ No newline at end of file
@@ -8,6 +8,9 @@
 //! - Predicate-based selection (`select_where`, `select_unique`, `select_duplicates`)
 //! - Structural analysis (`pairwise`, `all_equal`)
 //! - Counting and duplicate detection (`counter`)
+//! - Diagnostic reports (`forall_report`, `none_report`)
+//! - Result combinators (`all_of`, `any_of`)
+//! - Self-validating types (`Validate`, `forall_valid`)
 //! - Assertion macros (`assert_forall!`, `assert_unique!`, etc.)
 //!
 //! This crate focuses on enabling clear, declarative logic over iterables.
@@ -29,15 +32,24 @@
 #[macro_use]
 mod macros;
 
+pub mod combine;
 pub mod error;
-pub use error::QuantorError;
+pub use error::{QuantorError, QuantorErrors};
 
 pub mod quantifiers;
 pub mod prelude;
+pub mod report;
+pub mod validate;
 
 #[cfg(feature = "method-api")]
 pub mod quantor_ext;
 
+#[cfg(feature = "tracing")]
+pub mod trace;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
 pub use quantifiers::*;
 #[cfg(feature = "method-api")]
 pub use quantor_ext::*;
\ No newline at end of file
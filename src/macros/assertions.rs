@@ -1,15 +1,43 @@
 //! # quantor: Assertions
 //!
 //! This module provides macros for validating logical properties of sequences at runtime.
-//! All macros panic on failure and are suitable for use in tests or precondition enforcement.
+//!
+//! ## Check vs. assert
+//! The macros come in two flavors that share their arguments:
+//!
+//! - `assert_*!` macros panic on failure and are suitable for use in tests or precondition enforcement.
+//! - `check_*!` macros never panic. They expand to the `Result<(), QuantorError>` of the underlying
+//!   quantifier, so they can be propagated with `?` in `Result`-based test flows. An optional
+//!   string literal is attached as context via [`QuantorResultExt::context`](crate::error::QuantorResultExt::context).
+//!
+//! ```
+//! use quantor::{check_forall, check_sorted, QuantorError};
+//!
+//! fn validate(ids: &[u32]) -> Result<(), QuantorError> {
+//!     check_forall!(ids, |id| *id > 0, "ids must be positive")?;
+//!     check_sorted!(ids)?;
+//!     Ok(())
+//! }
+//!
+//! assert!(validate(&[1, 2, 3]).is_ok());
+//! assert!(validate(&[1, 0]).unwrap_err().to_string().starts_with("ids must be positive: "));
+//! ```
 //!
 //! ## Available Macros
 //!
 //! - [`assert_forall!`] - Ensures all elements satisfy a predicate
+//! - [`assert_forall_dbg!`] - Like `assert_forall!`, but prints the input on failure
 //! - [`assert_exists!`] - Ensures at least one element satisfies a predicate
 //! - [`assert_unique!`] - Ensures all elements are unique
 //! - [`assert_duplicates!`] - Ensures at least one duplicate is present
 //! - [`assert_pairwise!`] - Ensures a binary predicate holds for all adjacent pairs
+//! - [`assert_pairwise_dbg!`] - Like `assert_pairwise!`, but prints the offending pair on failure
+//! - [`assert_sorted!`] - Ensures a sequence is in non-decreasing order
+//! - [`assert_strictly_sorted!`] - Ensures a sequence is in strictly increasing order
+//! - [`assert_forallexists!`] - Ensures every element of one collection is matched in another, naming both on failure
+//! - [`assert_existsforall!`] - Ensures some element of one collection holds for all elements of another, naming both on failure
+//! - [`check_forall!`], [`check_exists!`], [`check_none!`], [`check_exactly_n!`], [`check_unique!`],
+//!   [`check_pairwise!`], [`check_sorted!`], [`check_strictly_sorted!`] - Non-panicking counterparts returning the `Result`
 
 /// Asserts that all elements in the collection satisfy the given predicate.
 /// ## Example
@@ -35,6 +63,46 @@ macro_rules! assert_forall {
         }
     }};
 }
+/// Asserts that all elements satisfy the predicate, printing the whole input on failure.
+///
+/// Behaves like [`assert_forall!`], but the panic message also contains the `Debug` output of
+/// the collection, which helps to inspect failures in CI without re-running locally.
+/// Accepts any iterable of references, such as `&xs` or `xs.iter()`, whose elements implement `Debug`.
+/// The items are collected before checking so they can be printed afterwards.
+/// ## Example
+/// ```
+/// use quantor::assert_forall_dbg;
+///
+/// let numbers = vec!(0, 2, 4, 6);
+///
+/// assert_forall_dbg!(&numbers, |x| x % 2 == 0);
+/// assert_forall_dbg!(&numbers, |x| *x < 10, "numbers must be single digits");
+/// assert_forall_dbg!(numbers.iter(), |x| *x >= 0);
+/// ```
+/// ```should_panic(expected = "input: [0, 2, 5]")
+/// use quantor::assert_forall_dbg;
+///
+/// assert_forall_dbg!(&vec!(0, 2, 5), |x| x % 2 == 0);
+/// ```
+#[macro_export]
+macro_rules! assert_forall_dbg {
+    ($iter:expr, $pred:expr) => {{
+        match ::std::iter::IntoIterator::into_iter($iter).collect::<::std::vec::Vec<_>>() {
+            items => match $crate::quantifiers::basic::forall(items.iter().copied(), $pred) {
+                Ok(()) => {},
+                Err(e) => panic!("assert_forall_dbg! failed: {}\n  input: {:?}", e, items),
+            },
+        }
+    }};
+    ($iter:expr, $pred:expr, $($msg:tt)+) => {{
+        match ::std::iter::IntoIterator::into_iter($iter).collect::<::std::vec::Vec<_>>() {
+            items => match $crate::quantifiers::basic::forall(items.iter().copied(), $pred) {
+                Ok(()) => {},
+                Err(e) => panic!("assert_forall_dbg! failed: {} — {}\n  input: {:?}", format_args!($($msg)+), e, items),
+            },
+        }
+    }};
+}
 /// Asserts that at least one element satisfies the predicate.
 /// ## Example
 /// ```
@@ -103,6 +171,8 @@ macro_rules! assert_none {
     }};
 }
 /// Asserts that all elements are unique.
+///
+/// Both forms check via [`all_distinct`](crate::quantifiers::basic::all_distinct) and only require `T: Eq + Hash`.
 /// ## Example
 /// ```
 /// use quantor::assert_unique;
@@ -111,23 +181,47 @@ macro_rules! assert_none {
 /// 
 /// assert_unique!(&numbers);
 /// ```
+/// Element types do not need to implement `Clone`:
+/// ```
+/// use quantor::assert_unique;
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Id(u32);
+///
+/// let ids = vec!(Id(1), Id(2));
+///
+/// assert_unique!(&ids);
+/// assert_unique!(&ids, "ids must be unique");
+/// ```
+/// ```should_panic
+/// use quantor::assert_unique;
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Id(u32);
+///
+/// assert_unique!(&vec!(Id(1), Id(1)));
+/// ```
+/// ```should_panic
+/// use quantor::assert_unique;
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// struct Id(u32);
+///
+/// assert_unique!(&vec!(Id(1), Id(1)), "ids must be unique");
+/// ```
 #[macro_export]
 macro_rules! assert_unique {
     ($iter:expr) => {{
-        let all_unique = $crate::select_duplicates($iter).is_empty();
-        assert!(all_unique, "assert_unique! failed: duplicate elements found.");
+        match $crate::quantifiers::basic::all_distinct($iter) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_unique! failed: {}", e),
+        }
     }};
     ($iter:expr, $($msg:tt)+) => {{
-        use std::collections::HashSet;
-        let mut set = HashSet::new();
-        let mut all_unique = true;
-        for item in $iter {
-            if !set.insert(item) {
-                all_unique = false;
-                break;
-            }
+        match $crate::quantifiers::basic::all_distinct($iter) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_unique! failed: {} — {}", format_args!($($msg)+), e),
         }
-        assert!(all_unique, $($msg)+);
     }};
 }
 /// Asserts that the input contains at least one duplicate.
@@ -173,4 +267,351 @@ macro_rules! assert_pairwise {
             Err(e) => panic!("assert_pairwise! failed: {} — {}", format_args!($($msg)+), e),
         }
     }};
-}
\ No newline at end of file
+}
+/// Asserts that a binary predicate holds for every adjacent pair, printing the offending pair on failure.
+///
+/// Behaves like [`assert_pairwise!`], but the panic message contains both indices and the `Debug`
+/// output of both values, e.g. `pairwise failed at (3, 4): 9 vs 2`. Requires `T: Debug`;
+/// use [`assert_pairwise!`] for element types without a `Debug` implementation.
+/// ## Example
+/// ```
+/// use quantor::assert_pairwise_dbg;
+///
+/// let numbers = vec!(0, 1, 2, 3);
+///
+/// assert_pairwise_dbg!(&numbers, |a, b| a < b);
+/// ```
+/// ```
+/// use quantor::assert_pairwise_dbg;
+///
+/// let panic = std::panic::catch_unwind(|| {
+///     assert_pairwise_dbg!(&vec!(1, 4, 6, 9, 2), |a, b| a < b, "timestamps must increase");
+/// }).unwrap_err();
+///
+/// let message = panic.downcast_ref::<String>().unwrap();
+/// assert!(message.contains("timestamps must increase — pairwise failed at (3, 4): 9 vs 2"));
+/// ```
+#[macro_export]
+macro_rules! assert_pairwise_dbg {
+    ($iter:expr, $pred:expr) => {{
+        match ::std::iter::IntoIterator::into_iter($iter).collect::<::std::vec::Vec<_>>() {
+            items => match $crate::quantifiers::structured::pairwise(items.iter().copied(), $pred) {
+                Ok(()) => {},
+                Err($crate::QuantorError::PairwiseFailed { index, .. }) => panic!(
+                    "assert_pairwise_dbg! failed: pairwise failed at ({}, {}): {:?} vs {:?}",
+                    index, index + 1, items[index], items[index + 1]
+                ),
+                Err(e) => panic!("assert_pairwise_dbg! failed: {}", e),
+            },
+        }
+    }};
+    ($iter:expr, $pred:expr, $($msg:tt)+) => {{
+        match ::std::iter::IntoIterator::into_iter($iter).collect::<::std::vec::Vec<_>>() {
+            items => match $crate::quantifiers::structured::pairwise(items.iter().copied(), $pred) {
+                Ok(()) => {},
+                Err($crate::QuantorError::PairwiseFailed { index, .. }) => panic!(
+                    "assert_pairwise_dbg! failed: {} — pairwise failed at ({}, {}): {:?} vs {:?}",
+                    format_args!($($msg)+), index, index + 1, items[index], items[index + 1]
+                ),
+                Err(e) => panic!("assert_pairwise_dbg! failed: {} — {}", format_args!($($msg)+), e),
+            },
+        }
+    }};
+}
+/// Asserts that a sequence is sorted in non-decreasing order.
+///
+/// Requires `T: PartialOrd`. The panic message contains the index of the first out-of-order pair.
+/// ## Example
+/// ```
+/// use quantor::assert_sorted;
+///
+/// let numbers = vec!(1, 2, 2, 5);
+///
+/// assert_sorted!(&numbers);
+/// assert_sorted!(&numbers, "timestamps must not go backwards");
+/// ```
+/// ```should_panic(expected = "adjacent pair starting at index 1")
+/// use quantor::assert_sorted;
+///
+/// assert_sorted!(&vec!(1, 5, 3));
+/// ```
+#[macro_export]
+macro_rules! assert_sorted {
+    ($iter:expr) => {{
+        match $crate::quantifiers::structured::pairwise($iter, |a, b| a <= b) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_sorted! failed: {}", e),
+        }
+    }};
+    ($iter:expr, $($msg:tt)+) => {{
+        match $crate::quantifiers::structured::pairwise($iter, |a, b| a <= b) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_sorted! failed: {} — {}", format_args!($($msg)+), e),
+        }
+    }};
+}
+/// Asserts that a sequence is sorted in strictly increasing order.
+///
+/// Requires `T: PartialOrd`. Checks via [`strictly_increasing`](crate::quantifiers::structured::strictly_increasing),
+/// so the panic message tells a repeated value apart from a decrease.
+/// ## Example
+/// ```
+/// use quantor::assert_strictly_sorted;
+///
+/// let ids = vec!(1, 4, 9);
+///
+/// assert_strictly_sorted!(&ids);
+/// ```
+/// ```should_panic(expected = "Duplicate at index 2")
+/// use quantor::assert_strictly_sorted;
+///
+/// assert_strictly_sorted!(&vec!(1, 4, 4), "ids must be ascending");
+/// ```
+#[macro_export]
+macro_rules! assert_strictly_sorted {
+    ($iter:expr) => {{
+        match $crate::quantifiers::structured::strictly_increasing($iter) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_strictly_sorted! failed: {}", e),
+        }
+    }};
+    ($iter:expr, $($msg:tt)+) => {{
+        match $crate::quantifiers::structured::strictly_increasing($iter) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_strictly_sorted! failed: {} — {}", format_args!($($msg)+), e),
+        }
+    }};
+}
+/// Asserts that every element of the first collection is matched by some element of the second.
+///
+/// Evaluates via [`quantify_labeled!`](crate::quantify_labeled), so the panic message names both
+/// collections and the bound variables.
+/// ## Example
+/// ```
+/// use quantor::assert_forallexists;
+///
+/// let requested = vec!(80, 443);
+/// let open = vec!(22, 80, 443);
+///
+/// assert_forallexists!(port in &requested, o in &open => port == o);
+/// assert_forallexists!(port in &requested, o in &open => port == o, "all requested ports must be open");
+/// ```
+/// ```should_panic(expected = "matches x at index 2 in")
+/// use quantor::assert_forallexists;
+///
+/// let a = vec!(1, 2, 3);
+/// let b = vec!(2, 3);
+///
+/// assert_forallexists!(x in &a, y in &b => x + 1 == *y);
+/// ```
+#[macro_export]
+macro_rules! assert_forallexists {
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {{
+        match $crate::quantify_labeled!(forallexists $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_forallexists! failed: {}", e),
+        }
+    }};
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr, $($msg:tt)+) => {{
+        match $crate::quantify_labeled!(forallexists $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_forallexists! failed: {} — {}", format_args!($($msg)+), e),
+        }
+    }};
+}
+/// Asserts that some element of the first collection satisfies the predicate for every element of the second.
+///
+/// Evaluates via [`quantify_labeled!`](crate::quantify_labeled), so the panic message names both
+/// collections and the bound variables.
+/// ## Example
+/// ```
+/// use quantor::assert_existsforall;
+///
+/// let xs = vec!(1, 5, 9);
+/// let ys = vec!(2, 3, 4);
+///
+/// assert_existsforall!(x in &xs, y in &ys => x > y);
+/// ```
+/// ```should_panic(expected = "satisfies the predicate for every y in")
+/// use quantor::assert_existsforall;
+///
+/// let xs = vec!(1, 2);
+/// let ys = vec!(2, 3, 4);
+///
+/// assert_existsforall!(x in &xs, y in &ys => x > y);
+/// ```
+#[macro_export]
+macro_rules! assert_existsforall {
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {{
+        match $crate::quantify_labeled!(existsforall $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_existsforall! failed: {}", e),
+        }
+    }};
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr, $($msg:tt)+) => {{
+        match $crate::quantify_labeled!(existsforall $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("assert_existsforall! failed: {} — {}", format_args!($($msg)+), e),
+        }
+    }};
+}
+/// Checks that all elements satisfy the predicate, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_forall!`].
+/// ## Example
+/// ```
+/// use quantor::{check_forall, error::QuantorResultExt};
+///
+/// let numbers = vec!(0, 2, 5);
+///
+/// assert_eq!(check_forall!(&numbers, |x| x % 2 == 0).failing_index(), Some(2));
+/// ```
+#[macro_export]
+macro_rules! check_forall {
+    ($iter:expr, $pred:expr) => {
+        $crate::quantifiers::basic::forall($iter, $pred)
+    };
+    ($iter:expr, $pred:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_forall!($iter, $pred), $msg)
+    };
+}
+/// Checks that at least one element satisfies the predicate, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_exists!`].
+/// ## Example
+/// ```
+/// use quantor::check_exists;
+///
+/// let numbers = vec!(1, 3, 5);
+///
+/// assert!(check_exists!(&numbers, |x| x % 2 == 0).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_exists {
+    ($iter:expr, $pred:expr) => {
+        $crate::quantifiers::basic::exists($iter, $pred)
+    };
+    ($iter:expr, $pred:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_exists!($iter, $pred), $msg)
+    };
+}
+/// Checks that no element satisfies the predicate, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_none!`].
+/// ## Example
+/// ```
+/// use quantor::check_none;
+///
+/// let values = vec![1, 3, 5];
+///
+/// assert!(check_none!(&values, |x| x % 2 == 0).is_ok());
+/// ```
+#[macro_export]
+macro_rules! check_none {
+    ($iter:expr, $pred:expr) => {
+        $crate::quantifiers::basic::none($iter, $pred)
+    };
+    ($iter:expr, $pred:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_none!($iter, $pred), $msg)
+    };
+}
+/// Checks the number of elements satisfying the predicate, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_exactly_n!`]. The count may be a range, see
+/// [`CountBound`](crate::quantifiers::basic::CountBound).
+/// ## Example
+/// ```
+/// use quantor::check_exactly_n;
+///
+/// let numbers = vec!(0, 1, 3, 5);
+///
+/// assert!(check_exactly_n!(&numbers, 1, |x| x % 2 == 0).is_ok());
+/// assert!(check_exactly_n!(&numbers, 2.., |x| x % 2 == 0).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_exactly_n {
+    ($iter:expr, $count:expr, $pred:expr) => {
+        $crate::quantifiers::basic::exactly_n($iter, $count, $pred)
+    };
+    ($iter:expr, $count:expr, $pred:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_exactly_n!($iter, $count, $pred), $msg)
+    };
+}
+/// Checks that all elements are unique, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_unique!`]. Requires `T: Eq + Hash`.
+/// ## Example
+/// ```
+/// use quantor::{check_unique, error::QuantorResultExt};
+///
+/// let numbers = vec!(0, 1, 1);
+///
+/// assert_eq!(check_unique!(&numbers).failing_index(), Some(2));
+/// ```
+#[macro_export]
+macro_rules! check_unique {
+    ($iter:expr) => {
+        $crate::quantifiers::basic::all_distinct($iter)
+    };
+    ($iter:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_unique!($iter), $msg)
+    };
+}
+/// Checks that a binary predicate holds for every adjacent pair, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_pairwise!`].
+/// ## Example
+/// ```
+/// use quantor::check_pairwise;
+///
+/// let numbers = vec!(0, 1, 2, 3);
+///
+/// assert!(check_pairwise!(&numbers, |a, b| a < b).is_ok());
+/// ```
+#[macro_export]
+macro_rules! check_pairwise {
+    ($iter:expr, $pred:expr) => {
+        $crate::quantifiers::structured::pairwise($iter, $pred)
+    };
+    ($iter:expr, $pred:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_pairwise!($iter, $pred), $msg)
+    };
+}
+/// Checks that a sequence is sorted in non-decreasing order, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_sorted!`]. Requires `T: PartialOrd`.
+/// ## Example
+/// ```
+/// use quantor::{check_sorted, error::QuantorResultExt};
+///
+/// assert_eq!(check_sorted!(&vec!(1, 5, 3)).failing_index(), Some(1));
+/// ```
+#[macro_export]
+macro_rules! check_sorted {
+    ($iter:expr) => {
+        $crate::quantifiers::structured::pairwise($iter, |a, b| a <= b)
+    };
+    ($iter:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_sorted!($iter), $msg)
+    };
+}
+/// Checks that a sequence is sorted in strictly increasing order, returning the result instead of panicking.
+///
+/// The non-panicking counterpart of [`assert_strictly_sorted!`]. Requires `T: PartialOrd`.
+/// ## Example
+/// ```
+/// use quantor::check_strictly_sorted;
+///
+/// let err = check_strictly_sorted!(&vec!(1, 4, 4), "ids must be ascending").unwrap_err();
+///
+/// assert!(err.to_string().starts_with("ids must be ascending: "));
+/// ```
+#[macro_export]
+macro_rules! check_strictly_sorted {
+    ($iter:expr) => {
+        $crate::quantifiers::structured::strictly_increasing($iter)
+    };
+    ($iter:expr, $msg:literal) => {
+        $crate::error::QuantorResultExt::context($crate::check_strictly_sorted!($iter), $msg)
+    };
+}
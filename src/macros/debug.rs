@@ -11,6 +11,10 @@
 //! - [`debug_assert_exists!`] – Asserts that at least one element satisfies a predicate.
 //! - [`debug_assert_none!`] – Asserts that no elements satisfy a predicate.
 //! - [`debug_assert_pairwise!`] – Asserts a binary predicate holds for all adjacent pairs.
+//! - [`debug_assert_sorted!`] – Asserts a sequence is in non-decreasing order.
+//! - [`debug_assert_strictly_sorted!`] – Asserts a sequence is in strictly increasing order.
+//! - [`debug_assert_forallexists!`] – Asserts every element of one collection is matched in another.
+//! - [`debug_assert_existsforall!`] – Asserts some element of one collection satisfies the predicate for all of another.
 //! - [`debug_assert_unique!`] – Asserts that all elements are unique.
 //! - [`debug_assert_duplicates!`] – Asserts that duplicates exist.
 //!
@@ -249,6 +253,97 @@ macro_rules! debug_assert_pairwise {
     };
 }
 
+/// Debug-only version of [`assert_sorted!`](crate::assert_sorted).
+/// Panics if the sequence is not in non-decreasing order, but only in debug builds.
+#[cfg(feature = "debug-tools")]
+#[macro_export]
+macro_rules! debug_assert_sorted {
+    ($xs:expr) => {
+        #[cfg(debug_assertions)]
+        match $crate::quantifiers::structured::pairwise($xs, |a, b| a <= b) {
+            Ok(()) => {},
+            Err(e) => panic!("debug_assert_sorted! failed: {}", e),
+        }
+    };
+}
+
+/// Debug-only version of [`assert_strictly_sorted!`](crate::assert_strictly_sorted).
+/// Panics if the sequence is not in strictly increasing order, but only in debug builds.
+#[cfg(feature = "debug-tools")]
+#[macro_export]
+macro_rules! debug_assert_strictly_sorted {
+    ($xs:expr) => {
+        #[cfg(debug_assertions)]
+        match $crate::quantifiers::structured::strictly_increasing($xs) {
+            Ok(()) => {},
+            Err(e) => panic!("debug_assert_strictly_sorted! failed: {}", e),
+        }
+    };
+}
+
+/// Debug-only version of [`assert_forallexists!`](crate::assert_forallexists).
+/// Panics if some element of the first collection has no match in the second, but only in debug builds.
+/// ## Example
+/// ```
+/// use quantor::debug_assert_forallexists;
+///
+/// let requested = vec!(80, 443);
+/// let open = vec!(22, 80, 443);
+///
+/// debug_assert_forallexists!(port in &requested, o in &open => port == o);
+/// ```
+/// ```should_panic(expected = "debug_assert_forallexists! failed: no y in")
+/// use quantor::debug_assert_forallexists;
+///
+/// let a = vec!(1, 2, 3);
+/// let b = vec!(2, 3);
+///
+/// debug_assert_forallexists!(x in &a, y in &b => x + 1 == *y);
+/// ```
+#[cfg(feature = "debug-tools")]
+#[macro_export]
+macro_rules! debug_assert_forallexists {
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
+        #[cfg(debug_assertions)]
+        match $crate::quantify_labeled!(forallexists $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("debug_assert_forallexists! failed: {}", e),
+        }
+    };
+}
+
+/// Debug-only version of [`assert_existsforall!`](crate::assert_existsforall).
+/// Panics if no element of the first collection satisfies the predicate for every element of the
+/// second, but only in debug builds.
+/// ## Example
+/// ```
+/// use quantor::debug_assert_existsforall;
+///
+/// let xs = vec!(1, 5, 9);
+/// let ys = vec!(2, 3, 4);
+///
+/// debug_assert_existsforall!(x in &xs, y in &ys => x > y);
+/// ```
+/// ```should_panic(expected = "debug_assert_existsforall! failed: no x in")
+/// use quantor::debug_assert_existsforall;
+///
+/// let xs = vec!(1, 2);
+/// let ys = vec!(2, 3, 4);
+///
+/// debug_assert_existsforall!(x in &xs, y in &ys => x > y);
+/// ```
+#[cfg(feature = "debug-tools")]
+#[macro_export]
+macro_rules! debug_assert_existsforall {
+    ($a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
+        #[cfg(debug_assertions)]
+        match $crate::quantify_labeled!(existsforall $a in $as, $b in $bs => $cond) {
+            Ok(()) => {},
+            Err(e) => panic!("debug_assert_existsforall! failed: {}", e),
+        }
+    };
+}
+
 /// Logs all adjacent pairs that violate the predicate.
 /// Does not panic. Active only in debug builds.
 #[cfg(feature = "debug-tools")]
@@ -14,6 +14,9 @@
 //! assert!(quantify!(exists x in (&numbers) => *x == 2).is_ok());
 //! ```
 //!
+//! - [`quantify_labeled!`] — Same syntax as `quantify!`, but failures name the collection that was checked.
+//! - [`quantify_bool!`] — Same syntax as `quantify!`, but yields a `bool` instead of a `Result`.
+//!
 //! For validation-specific macros like `assert_forall!` or `assert_duplicates!`, see the [`assertions`](crate::assertions) module.
 
 mod select;
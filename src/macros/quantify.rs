@@ -5,16 +5,49 @@
 ///
 /// ## Supported Quantifiers
 /// - `forall x in &a => predicate`
+/// - `forall x in &a => premise implies conclusion` (see [`implies`](crate::quantifiers::basic::implies))
+/// - `implies x in &a => premise, conclusion` (the same check with an explicit keyword)
 /// - `exists x in &a => predicate`
 /// - `none x in &a => predicate`
 /// - `exactly_one x in &a => predicate`
 /// - `exactly_n n x in &a => predicate`
+/// - `count n of x in &a => predicate` (synonym for `exactly_n`)
 /// - `all_equal x in &a => predicate`
 /// - `pairwise x,y in &a => predicate`
+/// - `window n w in &a => predicate` (binds `w` to a `&[&T]` slice of `n` adjacent elements)
 /// - `forallexists x in &a, y in &b => predicate`
 /// - `existsforall x in &a, y in &b => predicate`
+/// - `forallforall x in &a, y in &b => predicate`
+/// - `existsexists x in &a, y in &b => predicate`
+/// - `subset x in &a, y in &b` (every element of `a` appears in `b`)
+/// - `superset x in &a, y in &b` (every element of `b` appears in `a`)
 ///
 /// See the quantifier functions (e.g. [`forall`](crate::quantifiers::basic::forall)) for behavior.
+/// The result is always a `Result<(), QuantorError>`; use [`quantify_bool!`](crate::quantify_bool) for a plain `bool`.
+///
+/// ## Block predicates
+/// Every predicate may be a braced block, which allows `let` bindings, `match` expressions and
+/// other multi-statement logic. The block's final expression is the predicate result:
+/// ```rust
+/// use quantor::quantify;
+///
+/// let xs = vec!(1, 2, 3);
+/// let ys = vec!(4, 5, 6);
+///
+/// assert!(quantify!(forall x in &xs => {
+///     let doubled = x * 2;
+///     doubled < 10
+/// }).is_ok());
+///
+/// assert!(quantify!(exists x in &xs => { let (lo, hi) = (2, 3); *x > lo && *x <= hi }).is_ok());
+/// assert!(quantify!(none x in &xs => { match x { 0 => true, _ => false } }).is_ok());
+/// assert!(quantify!(exactly_one x in &xs => { let y = x + 1; y == 3 }).is_ok());
+/// assert!(quantify!(exactly_n 2 x in &xs => { let odd = x % 2 == 1; odd }).is_ok());
+/// assert!(quantify!(pairwise a,b in &xs => { let diff = b - a; diff == 1 }).is_ok());
+/// assert!(quantify!(window 2 w in &xs => { let sum: i32 = w.iter().copied().sum(); sum < 6 }).is_ok());
+/// assert!(quantify!(forallexists x in &xs, y in &ys => { let gap = y - x; gap == 3 }).is_ok());
+/// assert!(quantify!(existsforall x in &ys, y in &xs => { let max = 6; *x == max && y < x }).is_ok());
+/// ```
 ///
 /// ## Examples
 /// ```rust
@@ -32,6 +65,47 @@
 /// let a = vec!(1, 2);
 /// let b = vec!(3, 4);
 /// assert!(quantify!(forallexists x in &a, y in &b => x < y).is_ok());
+///
+/// let readings = vec!(1, 2, 3, 4);
+/// assert!(quantify!(window 3 w in &readings => w.iter().copied().sum::<i32>() < 10).is_ok());
+///
+/// let orders = vec!((true, 5), (false, 0), (true, 2));
+/// assert!(quantify!(forall o in &orders => o.0 implies o.1 > 0).is_ok());
+/// assert!(quantify!(forall o in &orders => o.0 implies o.1 > 3).is_err());
+///
+/// let small = vec!(1, 2);
+/// let large = vec!(5, 6);
+/// assert!(quantify!(forallforall x in &small, y in &large => x < y).is_ok());
+/// assert!(quantify!(existsexists x in &small, y in &large => x + y == 8).is_ok());
+///
+/// let required = vec!("id", "name");
+/// let columns = vec!("id", "name", "email");
+/// assert!(quantify!(subset r in &required, c in &columns).is_ok());
+/// assert!(quantify!(superset c in &columns, r in &required).is_ok());
+///
+/// // `count` and `exactly_n` are interchangeable.
+/// assert!(quantify!(count 2 of x in &readings => x % 2 == 0).is_ok());
+/// assert!(quantify!(exactly_n 2 x in &readings => x % 2 == 0).is_ok());
+/// ```
+///
+/// ## Implication
+/// `implies x in &a => premise, conclusion` maps to [`implies`](crate::quantifiers::basic::implies):
+/// the first expression is the premise, the second the conclusion, and both may use `x`. The check
+/// fails at the first element for which the premise holds but the conclusion does not.
+/// ```rust
+/// use quantor::{quantify, error::QuantorResultExt};
+///
+/// struct User { premium: bool, has_payment: bool }
+///
+/// let users = vec!(
+///     User { premium: true, has_payment: true },
+///     User { premium: false, has_payment: false },
+/// );
+/// assert!(quantify!(implies u in &users => u.premium, u.has_payment).is_ok());
+///
+/// let nums = vec!(4, 7, 9);
+/// let result = quantify!(implies x in &nums => *x > 5, x % 2 == 0);
+/// assert_eq!(result.failing_index(), Some(1));
 /// ```
 #[macro_export]
 macro_rules! quantify {
@@ -40,6 +114,14 @@ macro_rules! quantify {
         $crate::quantifiers::basic::forall($xs, |$x| $cond)
     };
 
+    (forall $x:ident in $xs:expr => $($rest:tt)+) => {
+        $crate::quantify!(@implies $x, $xs, [] $($rest)+)
+    };
+
+    (implies $x:ident in $xs:expr => $premise:expr, $conclusion:expr) => {
+        $crate::quantifiers::basic::implies($xs, |$x| $premise, |$x| $conclusion)
+    };
+
     (exists $x:ident in $xs:expr => $cond:expr) => {
         $crate::quantifiers::basic::exists($xs, |$x| $cond)
     };
@@ -56,6 +138,10 @@ macro_rules! quantify {
         $crate::quantifiers::basic::exactly_n($xs, $count, |$x| $cond)
     };
 
+    (count $count:literal of $x:ident in $xs:expr => $cond:expr) => {
+        $crate::quantifiers::basic::exactly_n($xs, $count, |$x| $cond)
+    };
+
     (all_equal $x:ident in $xs:expr) => {
         $crate::quantifiers::basic::all_equal($xs)
     };
@@ -64,6 +150,10 @@ macro_rules! quantify {
         $crate::quantifiers::structured::pairwise($xs, |$x, $y| $cond)
     };
 
+    (window $n:literal $w:ident in $xs:expr => $cond:expr) => {
+        $crate::quantifiers::structured::windowed($xs, $n, |$w| $cond)
+    };
+
     // Nested
     (existsforall $a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
         $crate::quantifiers::nested::existsforall($as, $bs, |$a, $b| $cond)
@@ -73,7 +163,166 @@ macro_rules! quantify {
         $crate::quantifiers::nested::forallexists($as, $bs, |$a, $b| $cond)
     };
 
+    (forallforall $a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
+        $crate::quantifiers::nested::forallforall($as, $bs, |$a, $b| $cond)
+    };
+
+    (existsexists $a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
+        $crate::quantifiers::nested::existsexists($as, $bs, |$a, $b| $cond)
+    };
+
+    // Set relations
+    (subset $a:ident in $as:expr, $b:ident in $bs:expr) => {
+        $crate::quantifiers::nested::is_subset($as, $bs)
+    };
+
+    (superset $a:ident in $as:expr, $b:ident in $bs:expr) => {
+        $crate::quantifiers::nested::is_superset($as, $bs)
+    };
+
+    // Splits `premise implies conclusion` at the `implies` keyword.
+    (@implies $x:ident, $xs:expr, [$($p:tt)+] implies $($q:tt)+) => {
+        $crate::quantifiers::basic::implies($xs, |$x| ($($p)+), |$x| ($($q)+))
+    };
+
+    (@implies $x:ident, $xs:expr, [$($p:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::quantify!(@implies $x, $xs, [$($p)* $t] $($rest)*)
+    };
+
     ($($t:tt)*) => {
         compile_error!("Invalid syntax in quantify! macro.");
     };
 }
+
+/// Evaluates a quantifier like [`quantify!`](crate::quantify), naming the collection on failure.
+///
+/// Accepts the same syntax as `quantify!`. On failure, the error is wrapped in
+/// [`QuantorError::Labeled`](crate::QuantorError::Labeled), which records the collection expression
+/// written after `in` (without a leading `&`).
+///
+/// For the nested quantifiers `forallexists`, `existsforall`, `forallforall` and `existsexists`,
+/// the error is wrapped in [`QuantorError::LabeledNested`](crate::QuantorError::LabeledNested)
+/// instead, which names both collections and their bound variables. For `subset` and `superset`,
+/// the first collection is used.
+///
+/// The wrapped error still reports the original kind and index through
+/// [`QuantorResultExt`](crate::error::QuantorResultExt).
+///
+/// ## Example
+/// ```rust
+/// use quantor::{quantify_labeled, error::QuantorResultExt};
+///
+/// let users = vec!(20, 35, 17);
+/// let result = quantify_labeled!(forall u in &users => *u >= 18);
+///
+/// assert_eq!(result.failing_index(), Some(2));
+/// assert_eq!(result.unwrap_err().to_string(), "forall over `users` failed at index 2.");
+/// ```
+///
+/// Nested quantifiers name both collections:
+/// ```rust
+/// use quantor::{quantify_labeled, error::QuantorResultExt};
+///
+/// let a = vec!(1, 2, 3);
+/// let b = vec!(2, 3);
+/// let result = quantify_labeled!(forallexists x in &a, y in &b => x + 1 == *y);
+///
+/// assert_eq!(result.failing_index(), Some(2));
+/// assert_eq!(result.unwrap_err().to_string(), "no y in `b` matches x at index 2 in `a`.");
+/// ```
+#[macro_export]
+macro_rules! quantify_labeled {
+    (exactly_n $count:literal $x:ident in $xs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(exactly_n $count $x in $xs => $cond))
+    };
+
+    (count $count:literal of $x:ident in $xs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(count $count of $x in $xs => $cond))
+    };
+
+    (all_equal $x:ident in $xs:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(all_equal $x in $xs))
+    };
+
+    (pairwise $x:ident,$y:ident in $xs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(pairwise $x,$y in $xs => $cond))
+    };
+
+    (window $n:literal $w:ident in $xs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(window $n $w in $xs => $cond))
+    };
+
+    (implies $x:ident in $xs:expr => $premise:expr, $conclusion:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(implies $x in $xs => $premise, $conclusion))
+    };
+
+    (subset $a:ident in $as:expr, $b:ident in $bs:expr) => {
+        $crate::quantify_labeled!(@label $as, $crate::quantify!(subset $a in $as, $b in $bs))
+    };
+
+    (superset $a:ident in $as:expr, $b:ident in $bs:expr) => {
+        $crate::quantify_labeled!(@label $as, $crate::quantify!(superset $a in $as, $b in $bs))
+    };
+
+    ($q:ident $a:ident in $as:expr, $b:ident in $bs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label_nested $a, $as, $b, $bs, $crate::quantify!($q $a in $as, $b in $bs => $cond))
+    };
+
+    ($q:ident $x:ident in $xs:expr => $cond:expr) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!($q $x in $xs => $cond))
+    };
+
+    (forall $x:ident in $xs:expr => $($rest:tt)+) => {
+        $crate::quantify_labeled!(@label $xs, $crate::quantify!(forall $x in $xs => $($rest)+))
+    };
+
+    (@label $xs:expr, $result:expr) => {
+        $result.map_err(|e| $crate::QuantorError::Labeled {
+            label: stringify!($xs).trim_start_matches('&').trim_start(),
+            source: ::std::boxed::Box::new(e),
+        })
+    };
+
+    (@label_nested $a:ident, $as:expr, $b:ident, $bs:expr, $result:expr) => {
+        $result.map_err(|e| $crate::QuantorError::LabeledNested {
+            outer: stringify!($as).trim_start_matches('&').trim_start(),
+            outer_var: stringify!($a),
+            inner: stringify!($bs).trim_start_matches('&').trim_start(),
+            inner_var: stringify!($b),
+            source: ::std::boxed::Box::new(e),
+        })
+    };
+
+    ($($t:tt)*) => {
+        compile_error!("Invalid syntax in quantify_labeled! macro.");
+    };
+}
+
+/// Evaluates a quantifier like [`quantify!`](crate::quantify), returning a plain `bool`.
+///
+/// Accepts the same syntax as `quantify!`, but discards the error detail: the result is `true`
+/// exactly when `quantify!` would return `Ok`. This mirrors `Iterator::all` and `Iterator::any` and
+/// eases migrating conditions written with them.
+///
+/// Use `quantify!` when the failing index or kind matters, e.g. for error reporting or with `?`;
+/// use `quantify_bool!` in plain `if` conditions and filters where only the outcome is needed.
+///
+/// ## Example
+/// ```rust
+/// use quantor::{quantify, quantify_bool};
+///
+/// let xs = vec!(2, 4, 6);
+///
+/// assert_eq!(quantify_bool!(forall x in &xs => x % 2 == 0), xs.iter().all(|x| x % 2 == 0));
+/// assert_eq!(quantify_bool!(exists x in &xs => *x > 5), xs.iter().any(|x| *x > 5));
+///
+/// if !quantify_bool!(none x in &xs => *x == 4) {
+///     assert!(quantify!(none x in &xs => *x == 4).is_err());
+/// }
+/// ```
+#[macro_export]
+macro_rules! quantify_bool {
+    ($($t:tt)*) => {
+        $crate::quantify!($($t)*).is_ok()
+    };
+}
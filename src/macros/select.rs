@@ -7,6 +7,10 @@
 /// - `select!(where x in xs => condition)` — equivalent to `select_where(xs, |x| condition)`
 /// - `select!(unique x in xs)` — equivalent to `select_unique(xs)`
 /// - `select!(duplicates x in xs)` — equivalent to `select_duplicates(xs)`
+/// - `select!(duplicates x in xs by key)` — equivalent to `select_duplicates_by(xs, |x| key)`
+///
+/// The key is taken by value, so it may be computed (e.g. `u.email.to_lowercase()`). Borrow fields
+/// that should not be cloned, e.g. `by &u.email`.
 ///
 /// ## Examples
 /// ```
@@ -17,6 +21,25 @@
 /// let evens    = select!(where x in &xs => x % 2 == 0);
 /// let uniques  = select!(unique x in &xs => *x > 0);
 /// let dups     = select!(duplicates x in &xs);
+///
+/// let users = vec![("ann", "a@x.io"), ("bob", "b@x.io"), ("amy", "a@x.io")];
+/// let shared = select!(duplicates u in &users by u.1);
+/// assert_eq!(shared, vec![&("ann", "a@x.io")]);
+///
+/// struct User { name: &'static str, email: String }
+///
+/// let us = vec![
+///     User { name: "ann", email: "Ann@x.io".into() },
+///     User { name: "bob", email: "bob@x.io".into() },
+///     User { name: "amy", email: "ann@X.io".into() },
+/// ];
+///
+/// let same_email = select!(duplicates u in &us by u.email.to_lowercase());
+/// assert_eq!(same_email.len(), 1);
+/// assert_eq!(same_email[0].name, "ann");
+///
+/// let exact_email = select!(duplicates u in &us by &u.email);
+/// assert!(exact_email.is_empty());
 /// ```
 #[macro_export]
 macro_rules! select {
@@ -32,6 +55,19 @@ macro_rules! select {
         $crate::select_duplicates($xs)
     };
 
+    (duplicates $x:ident in $($rest:tt)+) => {
+        $crate::select!(@duplicates_by $x, [] $($rest)+)
+    };
+
+    // Splits `xs by key` at the `by` keyword.
+    (@duplicates_by $x:ident, [$($xs:tt)+] by $($key:tt)+) => {
+        $crate::select_duplicates_by($($xs)+, |$x| $($key)+)
+    };
+
+    (@duplicates_by $x:ident, [$($xs:tt)*] $t:tt $($rest:tt)*) => {
+        $crate::select!(@duplicates_by $x, [$($xs)* $t] $($rest)*)
+    };
+
     // Catch all
     ($($t:tt)*) => {
         compile_error!("Invalid syntax in select! macro.");
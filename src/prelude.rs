@@ -2,11 +2,55 @@
 //!
 //! Includes all quantifiers and extension traits.
 //! Import this to bring the full quantor interface into scope.
+//! Import [`strict`] instead to treat empty input as a failure.
 
+pub use crate::combine::*;
 pub use crate::quantifiers::basic::*;
+pub use crate::quantifiers::helpers::*;
 pub use crate::quantifiers::nested::*;
 pub use crate::quantifiers::structured::*;
 pub use crate::quantifiers::selection::*;
+pub use crate::quantifiers::strings::*;
+pub use crate::report::*;
+pub use crate::validate::*;
 
 #[cfg(feature = "method-api")]
-pub use crate::quantor_ext::QuantorExt;
\ No newline at end of file
+pub use crate::quantor_ext::QuantorExt;
+
+#[cfg(feature = "tracing")]
+pub use crate::trace::TraceExt;
+
+/// Strict-mode prelude, in which empty input is a failure.
+///
+/// Re-exports everything from [`crate::prelude`], but replaces the quantifiers that succeed
+/// vacuously on empty input with their `*_nonempty` counterparts under the plain names:
+///
+/// | Name        | Resolves to            | Empty input                            |
+/// |-------------|------------------------|----------------------------------------|
+/// | `forall`    | [`forall_nonempty`]    | `Err(QuantorError::EmptyInput { .. })` |
+/// | `none`      | [`none_nonempty`]      | `Err(QuantorError::EmptyInput { .. })` |
+/// | `all_equal` | [`all_equal_nonempty`] | `Err(QuantorError::EmptyInput { .. })` |
+/// | `pairwise`  | [`pairwise_nonempty`]  | `Err(QuantorError::EmptyInput { .. })` |
+///
+/// `exists` and `exactly_one` already fail on empty input and are re-exported unchanged.
+///
+/// **Note**: _Only the free functions are replaced. The `QuantorExt`
+/// methods, the `quantify!` macro and the assertion macros keep their vacuous-truth semantics._
+///
+/// ## Example
+/// ```
+/// use quantor::prelude::strict::*;
+///
+/// let empty: Vec<i32> = vec![];
+///
+/// assert!(forall(&empty, |x| *x > 0).is_err());
+/// assert!(forall(&[1, 2], |x| *x > 0).is_ok());
+/// ```
+pub mod strict {
+    pub use super::*;
+
+    pub use crate::quantifiers::basic::all_equal_nonempty as all_equal;
+    pub use crate::quantifiers::basic::forall_nonempty as forall;
+    pub use crate::quantifiers::basic::none_nonempty as none;
+    pub use crate::quantifiers::basic::pairwise_nonempty as pairwise;
+}
@@ -2,8 +2,34 @@
 //! These functions express simple logical evaluations over a single iterable.
 //!
 //! Useful in validation, invariant checks, and test assertions.
+//!
+//! ## Empty input
+//! Quantifiers follow the usual logical conventions when given an empty collection:
+//!
+//! | Quantifier    | Empty input                              |
+//! |---------------|------------------------------------------|
+//! | `forall`      | `Ok(())` (vacuous truth)                 |
+//! | `exists`      | `Err(QuantorError::NoMatch { .. })`      |
+//! | `none`        | `Ok(())` (vacuous truth)                 |
+//! | `implies`     | `Ok(())` (vacuous truth)                 |
+//! | `forall_with_aggregate` | `Ok(())` if `agg_check(&agg_init)`, otherwise an error |
+//! | `exactly_one` | `Err(QuantorError::EmptyInput { .. })`   |
+//! | `exactly_n`   | `Ok(())` if `n` admits 0, otherwise an error |
+//! | `all_equal`   | `Ok(())` (vacuous truth)                 |
+//! | `pairwise`    | `Ok(())` (vacuous truth)                 |
+//!
+//! When "no data" should itself be a validation failure, use the strict `*_nonempty` variants
+//! (`forall_nonempty`, `none_nonempty`, `all_equal_nonempty`, `pairwise_nonempty`), which return
+//! `Err(QuantorError::EmptyInput { kind })` instead of succeeding vacuously.
+//!
+//! ## Indices
+//! Reported indices are positions in the iteration order of the input. For an adapted iterator
+//! such as `xs.iter().rev()`, that is not the position in `xs`. The slice-based `*_rev` variants
+//! (`forall_rev`, `none_rev`) evaluate from the back and report indices into the original slice.
 
-use crate::{error::{QuantorKind}, QuantorError};
+use std::{cmp::Ordering, collections::HashSet, fmt::Debug, hash::Hash, ops::{RangeFrom, RangeInclusive, RangeTo, Sub}, panic::{self, RefUnwindSafe}};
+
+use crate::{error::{Comparison, QuantorKind}, quantifiers::structured::pairwise, QuantorError, QuantorErrors};
 
 /// Checks if all elements satisfy the predicate.
 /// 
@@ -29,12 +55,22 @@ use crate::{error::{QuantorKind}, QuantorError};
 ///     assert_eq!(1, index);
 /// }
 /// ```
+/// The predicate may mutate captured state, as elements are visited sequentially:
+/// ```
+/// use quantor::quantifiers::forall;
+///
+/// let budget = vec!(10, 20, 30);
+/// let mut total = 0;
+///
+/// assert!(forall(&budget, |x| { total += x; total <= 60 }).is_ok());
+/// assert_eq!(total, 60);
+/// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn forall<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+pub fn forall<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
     for (i, item) in iter.into_iter().enumerate() {
         if !pred(item) {
@@ -45,230 +81,1401 @@ where
     Ok(())
 }
 
-/// Checks if at least one element satisfies the predicate.
-/// 
-/// Equivalent to **_∃a ∈ iter: pred(a)_**.
-/// When `a` is `∅`, this returns `QuantorError` because there is no element that can satisfy `pred`.
+/// Checks if all elements satisfy the predicate, reporting every element that fails it.
+///
+/// Equivalent to **_∀a ∈ iter: pred(a)_**.
+/// Unlike [`forall`], this does not stop at the first counterexample but evaluates every element,
+/// so all of them can be reported at once.
+///
+/// [`report::forall_report`](crate::report::forall_report) returns the failing *values* in a
+/// [`ForallReport`](crate::report::ForallReport) instead; this function returns [`QuantorErrors`].
+/// See [`none_collect`] for when to use which.
 /// ## Arguments
 /// - `iter` - The collection to be checked.
 /// - `pred` - The predicate to test each element against.
 /// ## Returns
-/// - `Ok(())` if any element satisfies the predicate.
-/// - `Err(QuantorError::NoMatch { kind })` if no element satisfies the predicate.
+/// - `Ok(())` if all elements satisfy the predicate.
+/// - `Err(QuantorErrors)` with one `QuantorError::PredicateFailed { kind, index }` per failing element, in order.
+///
 /// ## Example
 /// ```
-/// use quantor::quantifiers::exists;
-/// 
-/// let numbers = vec!(0, 1, 3, 5);
-/// assert!(exists(&numbers, |x| x % 2 == 0).is_ok());
-/// 
-/// let bad = vec!(1, 3, 5, 7);
-/// assert!(exists(&bad, |x| x % 2 == 0).is_err());
+/// use quantor::{quantifiers::forall_collect, error::QuantorResultExt};
+///
+/// let ports = vec![80, 70000, 443, 99999];
+///
+/// let result = forall_collect(&ports, |p| *p <= 65535);
+/// assert_eq!(result.failing_indices(), vec![1, 3]);
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn exists<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+pub fn forall_collect<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorErrors>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
-    for item in iter {
-        if pred(item) {
-            return Ok(());
+    iter.into_iter()
+        .enumerate()
+        .filter(|(_, item)| !pred(item))
+        .map(|(index, _)| QuantorError::PredicateFailed { kind: QuantorKind::Forall, index })
+        .collect::<QuantorErrors>()
+        .into_result()
+}
+
+/// Checks if an optional value, when present, satisfies the predicate.
+///
+/// Equivalent to **_∀a ∈ opt: pred(a)_**, treating the option as a collection of zero or one element.
+/// `None` is vacuously `Ok(())`, in line with `forall` on an empty collection.
+/// ## Arguments
+/// - `opt` - The optional value to be checked.
+/// - `pred` - The predicate to test the value against.
+/// ## Returns
+/// - `Ok(())` if the option is `None` or its value satisfies the predicate.
+/// - `Err(QuantorError::PredicateFailed { kind, index: 0 })` if the value fails the predicate.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::forall_option;
+///
+/// let port: Option<u32> = Some(8080);
+/// let unset: Option<u32> = None;
+///
+/// assert!(forall_option(&port, |p| *p <= 65535).is_ok());
+/// assert!(forall_option(&unset, |p| *p <= 65535).is_ok());
+/// assert!(forall_option(&Some(70000), |p| *p <= 65535).is_err());
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_option<T, F>(opt: &Option<T>, pred: F) -> Result<(), QuantorError>
+where
+    F: FnMut(&T) -> bool,
+{
+    forall(opt, pred)
+}
+
+/// Checks if all elements satisfy a predicate that explains its rejections.
+///
+/// Equivalent to **_∀a ∈ iter: pred(a)_**, where the predicate returns `Err(reason)` instead of `false`.
+///
+/// Useful for user-facing validation, where each element can fail for a different, domain-specific reason.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate returning `Ok(())` for valid elements and `Err(reason)` otherwise.
+/// ## Returns
+/// - `Ok(())` if the predicate accepts every element.
+/// - `Err(QuantorError::PredicateFailedReason { kind, index, reason })` for the first rejected element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_reasoned, error::QuantorResultExt};
+///
+/// let usernames = vec!("alice", "bo", "carol");
+///
+/// let result = forall_reasoned(&usernames, |name| {
+///     if name.len() < 3 {
+///         return Err(format!("`{}` is shorter than 3 characters", name));
+///     }
+///     Ok(())
+/// });
+///
+/// assert_eq!(result.failing_index(), Some(1));
+/// assert_eq!(
+///     result.unwrap_err().to_string(),
+///     "Predicate failed for element at index 1 of quantifier forall: `bo` is shorter than 3 characters"
+/// );
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_reasoned<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> Result<(), String>,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        if let Err(reason) = pred(item) {
+            return Err(QuantorError::PredicateFailedReason { kind: QuantorKind::Forall, index, reason });
         }
     }
 
-    Err(QuantorError::NoMatch {kind: QuantorKind::Exists})
+    Ok(())
 }
 
-/// Checks if no element satisfies the predicate.
-/// 
-/// Equivalent to **_∀a ∈ iter: ¬pred(a)_**.
+/// Checks if all elements satisfy the predicate, returning the first counterexample on failure.
+///
+/// Equivalent to **_∀a ∈ iter: pred(a)_**.
+///
+/// This performs the same check as [`forall`], but hands back the failing element with its index.
 /// ## Arguments
 /// - `iter` - The collection to be checked.
 /// - `pred` - The predicate to test each element against.
 /// ## Returns
-/// - `Ok(())` if no elements satisfy the predicate.
-/// - `Err(QuantorError::UnexpectedMatch { kind, index })` if at least one element satisfies the predicate, with the `index`.
-///
+/// - `Ok(())` if all elements satisfy the predicate.
+/// - `Err((index, &T))` with the first element that fails the predicate.
 /// ## Example
 /// ```
-/// use quantor::{quantifiers::none, error::QuantorResultExt};
-/// 
-/// let numbers = vec!(1, 3, 5, 7);
-/// assert!(none(&numbers, |x| x % 2 == 0).is_ok());
-/// 
-/// let bad = vec!(1, 3, 5, 7, 0);
-/// let err = none(&bad, |x| x % 2 == 0);
-/// 
-/// if let Some(index) = err.failing_index() {
-///     assert_eq!(4, index);
-/// }
+/// use quantor::quantifiers::forall_value;
+///
+/// let ports = vec!(80, 443, 70000);
+///
+/// assert_eq!(forall_value(&ports, |p| *p <= 65535), Err((2, &70000)));
+/// assert_eq!(forall_value(&ports, |p| *p > 0), Ok(()));
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn none<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+pub fn forall_value<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), (usize, &'a T)>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
     for (index, item) in iter.into_iter().enumerate() {
-        if pred(item) {
-            return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::None, index });
+        if !pred(item) {
+            return Err((index, item));
         }
     }
 
     Ok(())
 }
 
-/// Checks if exactly one element satisfies the predicate.
-/// 
-/// Equivalent to **_∃!a ∈ iter: pred(a)_**.
+/// Checks if all elements of a slice satisfy the predicate, reporting neighboring values on failure.
+///
+/// Equivalent to **_∀a ∈ slice: pred(a)_**.
+///
+/// Behaves like [`forall`], but the error includes the `Debug` output of up to `radius` elements on
+/// each side of the failing element, which helps to locate failures deep inside large inputs.
 /// ## Arguments
-/// - `iter` - The collection to be checked.
+/// - `slice` - The slice to be checked.
 /// - `pred` - The predicate to test each element against.
+/// - `radius` - The number of neighbors to include on each side of the failing element.
 /// ## Returns
-/// - `Ok(())` if exactly one element satisfies the predicate.
-/// - `Err(QuantorError::UnexpectedMatch { kind, index })` when there is more than one element which satisfies the predicate, with the `index` of the second passing element.
+/// - `Ok(())` if all elements satisfy the predicate.
+/// - `Err(QuantorError::PredicateFailedWithContext { kind, index, start, nearby, len })` for the first failing element.
 /// ## Example
 /// ```
-/// use quantor::{quantifiers::exactly_one, error::QuantorResultExt};
-/// 
-/// let numbers = vec!(0, 1, 3, 5);
-/// assert!(exactly_one(&numbers, |x| x % 2 == 0).is_ok());
-/// 
-/// let bad = vec!(0, 1, 3, 5, 6);
-/// let err = exactly_one(&bad, |x| x % 2 == 0);
-/// 
-/// if let Some(index) = err.failing_index() {
-///     assert_eq!(4, index);
-/// }
+/// use quantor::quantifiers::forall_context;
+///
+/// let readings: Vec<i32> = (0..100).map(|i| if i == 50 { -1 } else { i }).collect();
+///
+/// let err = forall_context(&readings, |r| *r >= 0, 1).unwrap_err();
+///
+/// assert_eq!(
+///     err.to_string(),
+///     "Predicate failed for element at index 50 of quantifier forall; nearby: [.., 49, **-1**, 51, ..]"
+/// );
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn exactly_one<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+pub fn forall_context<T, F>(slice: &[T], mut pred: F, radius: usize) -> Result<(), QuantorError>
 where
-    I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    T: Debug,
+    F: FnMut(&T) -> bool,
 {
-    let mut iter = iter.into_iter().enumerate().peekable();
+    for (index, item) in slice.iter().enumerate() {
+        if !pred(item) {
+            let start = index.saturating_sub(radius);
+            let end = index.saturating_add(radius).saturating_add(1).min(slice.len());
 
-    // Check for empty input
-    if iter.peek().is_none() {
-        return Err(QuantorError::EmptyInput {
-            kind: QuantorKind::ExactlyOne,
-        });
+            return Err(QuantorError::PredicateFailedWithContext {
+                kind: QuantorKind::Forall,
+                index,
+                start,
+                nearby: slice[start..end].iter().map(|x| format!("{:?}", x)).collect(),
+                len: slice.len(),
+            });
+        }
     }
 
-    let mut matched = 0;
+    Ok(())
+}
 
-    for (index, item) in iter {
-        if pred(item) {
-            matched += 1;
-            if matched > 1 {
-                return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::ExactlyOne, index });
+/// Checks if all elements satisfy the predicate, converting predicate panics into errors.
+///
+/// Equivalent to **_∀a ∈ iter: pred(a)_**.
+///
+/// Each predicate call is wrapped in [`std::panic::catch_unwind`], so a panic on one element ends
+/// the check with an error instead of unwinding through the caller. This keeps a single malformed
+/// record from aborting a whole validation run.
+///
+/// Both the predicate and the element type must be [`RefUnwindSafe`]. This rules out predicates
+/// that capture interior-mutable state (e.g. `Cell` or `RefCell`), which could be observed in a
+/// broken state after a panic. The global panic hook still runs, so the panic message is printed as
+/// usual unless a custom hook is installed. Panics are only caught with `panic = "unwind"`.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if all elements satisfy the predicate.
+/// - `Err(QuantorError::PredicateFailed { kind, index })` if an element fails the predicate.
+/// - `Err(QuantorError::PredicatePanicked { kind, index, message })` if the predicate panics.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_catching, QuantorError};
+///
+/// let rows = vec!["1", "2", "x", "4"];
+///
+/// let result = forall_catching(&rows, |r| r.parse::<u32>().expect("not a number") > 0);
+///
+/// assert!(matches!(result, Err(QuantorError::PredicatePanicked { index: 2, .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_catching<'a, I, T, F>(iter: I, pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + RefUnwindSafe,
+    F: Fn(&T) -> bool + RefUnwindSafe,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        match panic::catch_unwind(|| pred(item)) {
+            Ok(true) => {},
+            Ok(false) => return Err(QuantorError::PredicateFailed { kind: QuantorKind::Forall, index }),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|msg| msg.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned());
+
+                return Err(QuantorError::PredicatePanicked { kind: QuantorKind::Forall, index, message });
             }
         }
     }
 
-    if matched == 1 {
-        Ok(())
-    } else {
-        Err(QuantorError::PredicateFailed {
-            kind: QuantorKind::ExactlyOne,
-            index: 0,
-        })
+    Ok(())
+}
+
+/// Checks if all elements of a slice satisfy the predicate, evaluating from the back.
+///
+/// Equivalent to **_∀a ∈ slice: pred(a)_**, checked in the order `aₙ₋₁, …, a₀`.
+///
+/// Quantifiers report indices as positions in the iteration order, so passing `xs.iter().rev()`
+/// to [`forall`] yields indices counted from the back. This function instead reports the index
+/// of the failing element in the original slice.
+/// ## Arguments
+/// - `slice` - The slice to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if all elements satisfy the predicate.
+/// - `Err(QuantorError::PredicateFailed { kind, index })` for the last failing element, with its index in `slice`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::{forall, forall_rev}, error::QuantorResultExt};
+///
+/// let numbers = vec!(1, -2, 3, -4, 5);
+///
+/// // Position in the reversed stream: `-4` is the second element visited.
+/// assert_eq!(forall(numbers.iter().rev(), |x| *x > 0).failing_index(), Some(1));
+///
+/// // Position in the original slice.
+/// assert_eq!(forall_rev(&numbers, |x| *x > 0).failing_index(), Some(3));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_rev<T, F>(slice: &[T], mut pred: F) -> Result<(), QuantorError>
+where
+    F: FnMut(&T) -> bool,
+{
+    for (index, item) in slice.iter().enumerate().rev() {
+        if !pred(item) {
+            return Err(QuantorError::PredicateFailed { kind: QuantorKind::Forall, index });
+        }
     }
+
+    Ok(())
 }
 
-/// Checks if all elements are equal to each other.
+/// Checks if at least one element satisfies the predicate.
 /// 
-/// Equivalent to **_∀a,b ∈ iter: a = b_**.
+/// Equivalent to **_∃a ∈ iter: pred(a)_**.
+/// When `a` is `∅`, this returns `QuantorError` because there is no element that can satisfy `pred`.
 /// ## Arguments
 /// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
 /// ## Returns
-/// - `Ok(())` if all elements are equal to each other.
-/// - `Err(QuantorError::NotAllEqual { kind, index })` if an element at `index` is not equal to the first element.
+/// - `Ok(())` if any element satisfies the predicate.
+/// - `Err(QuantorError::NoMatch { kind })` if no element satisfies the predicate.
 /// ## Example
 /// ```
-/// use quantor::{quantifiers::all_equal, error::QuantorResultExt};
-/// 
-/// let empty: Vec<usize> = vec!();
-/// 
-/// let ones    = vec!(1, 1, 1);
-/// let natural = vec!(1, 2, 3);
-/// 
-/// assert!(all_equal(&empty).is_ok());
-/// assert!(all_equal(&ones).is_ok());
+/// use quantor::quantifiers::exists;
 /// 
-/// let err = all_equal(&natural);
+/// let numbers = vec!(0, 1, 3, 5);
+/// assert!(exists(&numbers, |x| x % 2 == 0).is_ok());
 /// 
-/// if let Some(index) = err.failing_index() {
-///     assert_eq!(1, index);
-/// }
+/// let bad = vec!(1, 3, 5, 7);
+/// assert!(exists(&bad, |x| x % 2 == 0).is_err());
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn all_equal<'a, I, T>(iter: I) -> Result<(), QuantorError>
+pub fn exists<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
 where
     I: IntoIterator<Item = &'a T>,
-    T: 'a + Eq
+    F: FnMut(&T) -> bool,
 {
-    let mut iter = iter.into_iter();
-    if let Some(first) = iter.next() {
-        for (i, item) in iter.enumerate() {
-            if item != first {
-                return Err(QuantorError::NotAllEqual { kind: QuantorKind::AllEqual, index: i + 1 });
-            }
+    for item in iter {
+        if pred(item) {
+            return Ok(());
         }
     }
 
-    Ok(())
+    Err(QuantorError::NoMatch {kind: QuantorKind::Exists})
 }
 
-/// Checks if exactly `n` elements in the iterator satisfy the predicate.
+/// Returns the first element that satisfies the predicate.
 ///
-/// Equivalent to **_|{x ∈ iter | pred(x)}| = n_**
+/// Equivalent to **_∃a ∈ iter: pred(a)_**, yielding the witness `a`.
 ///
+/// This performs the same check as [`exists`], but hands back the matching element.
 /// ## Arguments
-/// - `iter` - The collection to be checked.
-/// - `n` - The number of assumed elements to satisfy `pred`.
+/// - `iter` - The collection to be searched.
 /// - `pred` - The predicate to test each element against.
 /// ## Returns
-/// - `Ok(())` if exactly `n` elements match.
-/// - `Err(QuantorError::ExactlyNFailed { kind, found, expected })` otherwise.
+/// - `Ok(&T)` with the first element that satisfies the predicate.
+/// - `Err(QuantorError::NoMatch { kind })` if no element satisfies the predicate.
 /// ## Example
 /// ```
-/// use quantor::quantifiers::exactly_n;
-/// use quantor::error::QuantorResultExt;
-///
-/// let values = vec![1, 2, 4, 6];
-/// let result = exactly_n(&values, 3, |x| x % 2 == 0);
-///
-/// assert!(result.is_ok());
+/// use quantor::quantifiers::exists_value;
 ///
-/// let err = exactly_n(&values, 2, |x| x % 2 == 0);
+/// let users = vec!(("ann", false), ("bob", true), ("cid", true));
 ///
-/// if let Some(expected) = err.match_count() {
-///     assert_eq!(expected, 3);
-/// }
+/// assert_eq!(exists_value(&users, |u| u.1), Ok(&("bob", true)));
+/// assert!(exists_value(&users, |u| u.0 == "dan").is_err());
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn exactly_n<'a, I, T: 'a, F>(
-    iter: I,
-    n: usize,
-    pred: F,
-) -> Result<(), QuantorError>
+pub fn exists_value<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<&'a T, QuantorError>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
-    let found = iter.into_iter().filter(|x| pred(x)).count();
-
-    if found == n {
-        Ok(())
-    } else {
-        Err(QuantorError::ExactlyNFailed { kind: QuantorKind::ExactlyN, found, expected: n })
+    for item in iter {
+        if pred(item) {
+            return Ok(item);
+        }
     }
+
+    Err(QuantorError::NoMatch { kind: QuantorKind::Exists })
+}
+
+/// Checks if no element satisfies the predicate.
+/// 
+/// Equivalent to **_∀a ∈ iter: ¬pred(a)_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if no elements satisfy the predicate.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` if at least one element satisfies the predicate, with the `index`.
+///
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::none, error::QuantorResultExt};
+/// 
+/// let numbers = vec!(1, 3, 5, 7);
+/// assert!(none(&numbers, |x| x % 2 == 0).is_ok());
+/// 
+/// let bad = vec!(1, 3, 5, 7, 0);
+/// let err = none(&bad, |x| x % 2 == 0);
+/// 
+/// if let Some(index) = err.failing_index() {
+///     assert_eq!(4, index);
+/// }
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn none<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        if pred(item) {
+            return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::None, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if no element satisfies the predicate, reporting every element that does.
+///
+/// Equivalent to **_∀a ∈ iter: ¬pred(a)_**.
+/// Unlike [`none`], this does not stop at the first match but evaluates every element, so all
+/// offenders can be reported at once.
+///
+/// [`report::none_report`](crate::report::none_report) also evaluates every element, but returns a
+/// [`ForallReport`](crate::report::ForallReport) with the offending *values*, for logging and
+/// inspection. This function returns the failures as [`QuantorErrors`], for propagating with `?`
+/// alongside other checks. It is named `*_collect` because the `*_report` names belong to the
+/// `ForallReport` family.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if no elements satisfy the predicate.
+/// - `Err(QuantorErrors)` with one `QuantorError::UnexpectedMatch { kind, index }` per matching element, in order.
+///
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::none_collect, error::QuantorResultExt};
+///
+/// let tags = vec!["ok", "forbidden", "fine", "forbidden"];
+/// assert!(none_collect(&tags, |t| *t == "banned").is_ok());
+///
+/// let result = none_collect(&tags, |t| *t == "forbidden");
+///
+/// assert_eq!(result.failing_indices(), vec![1, 3]);
+/// assert_eq!(result.unwrap_err().len(), 2);
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn none_collect<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorErrors>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    iter.into_iter()
+        .enumerate()
+        .filter(|(_, item)| pred(item))
+        .map(|(index, _)| QuantorError::UnexpectedMatch { kind: QuantorKind::None, index })
+        .collect::<QuantorErrors>()
+        .into_result()
+}
+
+/// Checks if no element of a slice satisfies the predicate, evaluating from the back.
+///
+/// Equivalent to **_∀a ∈ slice: ¬pred(a)_**, checked in the order `aₙ₋₁, …, a₀`.
+///
+/// Like [`forall_rev`], the reported index refers to the position in the original slice.
+/// ## Arguments
+/// - `slice` - The slice to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if no elements satisfy the predicate.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` for the last matching element, with its index in `slice`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::none_rev, error::QuantorResultExt};
+///
+/// let numbers = vec!(1, 0, 3, 0, 5);
+///
+/// assert_eq!(none_rev(&numbers, |x| *x == 0).failing_index(), Some(3));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn none_rev<T, F>(slice: &[T], mut pred: F) -> Result<(), QuantorError>
+where
+    F: FnMut(&T) -> bool,
+{
+    for (index, item) in slice.iter().enumerate().rev() {
+        if pred(item) {
+            return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::None, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if every element satisfying the premise also satisfies the conclusion.
+///
+/// Equivalent to **_∀a ∈ iter: premise(a) → conclusion(a)_**.
+///
+/// The conclusion is only evaluated for elements that satisfy the premise.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `premise` - The condition that triggers the rule.
+/// - `conclusion` - The condition that must hold whenever the premise does.
+/// ## Returns
+/// - `Ok(())` if no element satisfies the premise without satisfying the conclusion.
+/// - `Err(QuantorError::ImplicationViolated { kind, index })` for the first element where `premise(a) ∧ ¬conclusion(a)`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::implies, error::QuantorResultExt};
+///
+/// struct User { premium: bool, has_payment: bool }
+///
+/// let users = vec!(
+///     User { premium: true, has_payment: true },
+///     User { premium: false, has_payment: false },
+///     User { premium: true, has_payment: false },
+/// );
+///
+/// let result = implies(&users, |u| u.premium, |u| u.has_payment);
+///
+/// assert_eq!(result.failing_index(), Some(2));
+/// assert_eq!(result.unwrap_err().to_string(), "Implication violated at index 2 for quantifier implies.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn implies<'a, I, T: 'a, P, Q>(iter: I, mut premise: P, mut conclusion: Q) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    P: FnMut(&T) -> bool,
+    Q: FnMut(&T) -> bool,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        if premise(item) && !conclusion(item) {
+            return Err(QuantorError::ImplicationViolated { kind: QuantorKind::Implies, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if all elements satisfy the predicate, and if an aggregate folded over them passes a final check.
+///
+/// Equivalent to **_(∀a ∈ iter: pred(a)) ∧ check(fold(init, step, iter))_**.
+///
+/// Both conditions are evaluated in a single pass. The aggregate check only runs once every element
+/// has passed the predicate; on empty input it is applied to `agg_init` directly.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// - `agg_init` - The initial value of the aggregate.
+/// - `agg_step` - Folds an element into the aggregate.
+/// - `agg_check` - The condition the final aggregate must satisfy.
+/// ## Returns
+/// - `Ok(())` if all elements satisfy the predicate and the aggregate passes the check.
+/// - `Err(QuantorError::PredicateFailed { kind, index })` if an element fails the predicate, with the index of the first failure.
+/// - `Err(QuantorError::AggregateFailed { kind, len })` if every element passed, but the aggregate did not.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_with_aggregate, QuantorError};
+///
+/// let within_budget = |xs: &[i32]| forall_with_aggregate(xs, |x| *x >= 0, 0, |sum, x| sum + x, |sum| *sum <= 100);
+///
+/// assert!(within_budget(&[20, 30, 40]).is_ok());
+/// assert!(matches!(within_budget(&[20, -5, 40]), Err(QuantorError::PredicateFailed { index: 1, .. })));
+/// assert!(matches!(within_budget(&[50, 30, 40]), Err(QuantorError::AggregateFailed { len: 3, .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_with_aggregate<'a, I, T: 'a, A, F, S, C>(
+    iter: I,
+    mut pred: F,
+    agg_init: A,
+    mut agg_step: S,
+    agg_check: C,
+) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+    S: FnMut(A, &T) -> A,
+    C: FnOnce(&A) -> bool,
+{
+    let mut aggregate = agg_init;
+    let mut len = 0;
+
+    for (index, item) in iter.into_iter().enumerate() {
+        if !pred(item) {
+            return Err(QuantorError::PredicateFailed { kind: QuantorKind::ForallWithAggregate, index });
+        }
+        aggregate = agg_step(aggregate, item);
+        len += 1;
+    }
+
+    if agg_check(&aggregate) {
+        Ok(())
+    } else {
+        Err(QuantorError::AggregateFailed { kind: QuantorKind::ForallWithAggregate, len })
+    }
+}
+
+/// Checks if exactly one element satisfies the predicate.
+/// 
+/// Equivalent to **_∃!a ∈ iter: pred(a)_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if exactly one element satisfies the predicate.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` when there is more than one element which satisfies the predicate, with the `index` of the second passing element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::exactly_one, error::QuantorResultExt};
+/// 
+/// let numbers = vec!(0, 1, 3, 5);
+/// assert!(exactly_one(&numbers, |x| x % 2 == 0).is_ok());
+/// 
+/// let bad = vec!(0, 1, 3, 5, 6);
+/// let err = exactly_one(&bad, |x| x % 2 == 0);
+/// 
+/// if let Some(index) = err.failing_index() {
+///     assert_eq!(4, index);
+/// }
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn exactly_one<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter().enumerate().peekable();
+
+    // Check for empty input
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput {
+            kind: QuantorKind::ExactlyOne,
+        });
+    }
+
+    let mut matched = 0;
+
+    for (index, item) in iter {
+        if pred(item) {
+            matched += 1;
+            if matched > 1 {
+                return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::ExactlyOne, index });
+            }
+        }
+    }
+
+    if matched == 1 {
+        Ok(())
+    } else {
+        Err(QuantorError::PredicateFailed {
+            kind: QuantorKind::ExactlyOne,
+            index: 0,
+        })
+    }
+}
+
+/// Returns the single element that satisfies the predicate.
+///
+/// Equivalent to **_ιa ∈ iter: pred(a)_**, the unique `a` for which **_∃!a ∈ iter: pred(a)_** holds.
+///
+/// This performs the same check as [`exactly_one`], but hands back the matching element.
+/// ## Arguments
+/// - `iter` - The collection to be searched.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(&T)` with the only element that satisfies the predicate.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::NoMatch { kind })` if no element satisfies the predicate.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` if more than one element satisfies the predicate, with the `index` of the second match.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::the_one, QuantorError};
+///
+/// let configs = vec!(("dev", false), ("prod", true), ("test", false));
+///
+/// assert_eq!(the_one(&configs, |c| c.1), Ok(&("prod", true)));
+/// assert!(matches!(the_one(&configs, |c| !c.1), Err(QuantorError::UnexpectedMatch { index: 2, .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn the_one<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<&'a T, QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter().enumerate().peekable();
+
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput { kind: QuantorKind::ExactlyOne });
+    }
+
+    let mut found = None;
+
+    for (index, item) in iter {
+        if pred(item) {
+            if found.is_some() {
+                return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::ExactlyOne, index });
+            }
+            found = Some(item);
+        }
+    }
+
+    found.ok_or(QuantorError::NoMatch { kind: QuantorKind::ExactlyOne })
+}
+
+/// Checks if every element satisfies exactly one predicate out of a set.
+///
+/// Equivalent to **_∀a ∈ iter: |{p ∈ preds | p(a)}| = 1_**.
+///
+/// Closures of different types can be combined by passing `&dyn Fn(&T) -> bool` references.
+/// If `preds` is empty, every element matches zero predicates, so any non-empty input fails.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `preds` - The set of mutually exclusive predicates.
+/// ## Returns
+/// - `Ok(())` if every element satisfies exactly one predicate.
+/// - `Err(QuantorError::NotExactlyOneOf { kind, index, matched })` for the first element matching zero or several predicates.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::exactly_one_of, error::QuantorResultExt};
+///
+/// #[derive(PartialEq)]
+/// enum State { Pending, Running, Done }
+/// struct Task { state: State, finished: bool }
+///
+/// let pending = |t: &Task| t.state == State::Pending;
+/// let running = |t: &Task| t.state == State::Running;
+/// let done = |t: &Task| t.finished;
+/// let states: [&dyn Fn(&Task) -> bool; 3] = [&pending, &running, &done];
+///
+/// let tasks = vec!(
+///     Task { state: State::Pending, finished: false },
+///     Task { state: State::Running, finished: true },
+///     Task { state: State::Done, finished: true },
+/// );
+///
+/// let result = exactly_one_of(&tasks, &states);
+///
+/// assert_eq!(result.failing_index(), Some(1));
+/// assert_eq!(result.match_count(), Some(2));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn exactly_one_of<'a, I, T: 'a, F>(iter: I, preds: &[F]) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: Fn(&T) -> bool,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        let matched = preds.iter().filter(|pred| pred(item)).count();
+
+        if matched != 1 {
+            return Err(QuantorError::NotExactlyOneOf { kind: QuantorKind::ExactlyOneOf, index, matched });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if all elements are equal to each other.
+/// 
+/// Equivalent to **_∀a,b ∈ iter: a = b_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// ## Returns
+/// - `Ok(())` if all elements are equal to each other.
+/// - `Err(QuantorError::NotAllEqual { kind, index })` if an element at `index` is not equal to the first element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::all_equal, error::QuantorResultExt};
+/// 
+/// let empty: Vec<usize> = vec!();
+/// 
+/// let ones    = vec!(1, 1, 1);
+/// let natural = vec!(1, 2, 3);
+/// 
+/// assert!(all_equal(&empty).is_ok());
+/// assert!(all_equal(&ones).is_ok());
+/// 
+/// let err = all_equal(&natural);
+/// 
+/// if let Some(index) = err.failing_index() {
+///     assert_eq!(1, index);
+/// }
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_equal<'a, I, T>(iter: I) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq
+{
+    let mut iter = iter.into_iter();
+    if let Some(first) = iter.next() {
+        for (i, item) in iter.enumerate() {
+            if item != first {
+                return Err(QuantorError::NotAllEqual { kind: QuantorKind::AllEqual, index: i + 1 });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Counts how many leading elements are equal to the first element.
+///
+/// Diagnostic companion to [`all_equal`]: both stop at the first element that differs from the first,
+/// but this returns the length of the agreeing prefix instead of an error. If every element is equal
+/// the full length is returned, so `all_equal_prefix(xs) == xs.len()` exactly when `all_equal(xs)` succeeds.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// ## Returns
+/// - The number of elements before the first divergence, or `0` for empty input.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::{all_equal, all_equal_prefix}, error::QuantorResultExt};
+///
+/// let readings = vec!(7, 7, 7, 9, 7);
+///
+/// assert_eq!(all_equal_prefix(&readings), 3);
+/// assert_eq!(all_equal(&readings).failing_index(), Some(3));
+///
+/// assert_eq!(all_equal_prefix(&[1, 1, 1]), 3);
+/// assert_eq!(all_equal_prefix::<_, i32>(&[]), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn all_equal_prefix<'a, I, T>(iter: I) -> usize
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq
+{
+    let mut iter = iter.into_iter();
+    match iter.next() {
+        Some(first) => 1 + iter.take_while(|item| *item == first).count(),
+        None => 0,
+    }
+}
+
+/// Checks if all elements of a slice of `Copy` values are equal to each other.
+///
+/// Equivalent to **_∀a,b ∈ slice: a = b_**.
+///
+/// Produces the same result as [`all_equal`], but compares fixed-size blocks against a copy of the
+/// first value without short-circuiting inside a block. This lets the compiler vectorize the
+/// comparison, which is considerably faster on large slices of primitives such as `u8` or `u32`.
+/// Use [`all_equal`] for element types that are not `Copy`.
+/// ## Arguments
+/// - `slice` - The slice to be checked.
+/// ## Returns
+/// - `Ok(())` if all elements are equal to each other.
+/// - `Err(QuantorError::NotAllEqual { kind, index })` if an element at `index` is not equal to the first element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::{all_equal, all_equal_copy}, error::QuantorResultExt};
+///
+/// let mut bytes = vec![7u8; 1000];
+/// assert!(all_equal_copy(&bytes).is_ok());
+///
+/// bytes[613] = 0;
+/// assert_eq!(all_equal_copy(&bytes).failing_index(), Some(613));
+/// assert_eq!(all_equal_copy(&bytes), all_equal(&bytes));
+///
+/// let empty: [u32; 0] = [];
+/// assert_eq!(all_equal_copy(&empty), all_equal(&empty));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_equal_copy<T>(slice: &[T]) -> Result<(), QuantorError>
+where
+    T: Copy + Eq,
+{
+    const BLOCK: usize = 32;
+
+    let first = match slice.first() {
+        Some(&f) => f,
+        None => return Ok(()),
+    };
+
+    let mut offset = 0;
+    for block in slice.chunks_exact(BLOCK) {
+        if !block.iter().fold(true, |equal, &x| equal & (x == first)) {
+            break;
+        }
+        offset += BLOCK;
+    }
+
+    match slice[offset..].iter().position(|&x| x != first) {
+        Some(i) => Err(QuantorError::NotAllEqual { kind: QuantorKind::AllEqual, index: offset + i }),
+        None => Ok(()),
+    }
+}
+
+/// Checks if all elements are distinct from each other.
+///
+/// Equivalent to **_∀aᵢ,aⱼ ∈ iter: i ≠ j ⇒ aᵢ ≠ aⱼ_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// ## Returns
+/// - `Ok(())` if no element occurs more than once.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` with the `index` of the first element equal to an earlier one.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::all_distinct, error::QuantorResultExt};
+///
+/// let ids = vec!(1, 2, 3, 2);
+///
+/// assert!(all_distinct(&ids[..3]).is_ok());
+/// assert_eq!(all_distinct(&ids).failing_index(), Some(3));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_distinct<'a, I, T>(iter: I) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq + Hash,
+{
+    let mut seen = HashSet::new();
+
+    for (index, item) in iter.into_iter().enumerate() {
+        if !seen.insert(item) {
+            return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::AllDistinct, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if all elements are equal to each other under a key projection.
+///
+/// Equivalent to **_∀a,b ∈ iter: key(a) = key(b)_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `key` - The projection whose values are compared.
+/// ## Returns
+/// - `Ok(())` if all projected keys are equal.
+/// - `Err(QuantorError::NotAllEqual { kind, index })` if the key at `index` differs from the key of the first element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::all_equal_by, error::QuantorResultExt};
+///
+/// let records = vec!((1, "a"), (1, "b"), (2, "c"));
+///
+/// assert!(all_equal_by(&records[..2], |r| r.0).is_ok());
+/// assert_eq!(all_equal_by(&records, |r| r.0).failing_index(), Some(2));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_equal_by<'a, I, T: 'a, K, F>(iter: I, mut key: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    let mut iter = iter.into_iter();
+    if let Some(first) = iter.next() {
+        let first = key(first);
+        for (i, item) in iter.enumerate() {
+            if key(item) != first {
+                return Err(QuantorError::NotAllEqual { kind: QuantorKind::AllEqual, index: i + 1 });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if all elements are within `tolerance` of the first element.
+///
+/// Equivalent to **_∀a ∈ iter: |a - a₀| ≤ tolerance_**.
+///
+/// Works for any `Copy` type with subtraction and ordering, such as floats and integers.
+/// Since every element is compared against the first one, two elements may differ by up to `2 · tolerance`.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `tolerance` - The maximum allowed distance from the first element.
+/// ## Returns
+/// - `Ok(())` if all elements are within `tolerance` of the first element.
+/// - `Err(QuantorError::NotAllEqual { kind, index })` for the first element further away.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::all_close, error::QuantorResultExt};
+///
+/// let readings = vec!(1.00, 1.004, 0.998);
+///
+/// assert!(all_close(&readings, 0.01).is_ok());
+/// assert_eq!(all_close(&[1.0, 1.0, 1.5], 0.01).failing_index(), Some(2));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_close<'a, I, T>(iter: I, tolerance: T) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Copy + PartialOrd + Sub<Output = T>,
+{
+    let mut iter = iter.into_iter();
+    if let Some(&first) = iter.next() {
+        for (i, &item) in iter.enumerate() {
+            let distance = if item > first { item - first } else { first - item };
+            // Incomparable values (e.g. NaN) are never close.
+            let close = matches!(distance.partial_cmp(&tolerance), Some(Ordering::Less | Ordering::Equal));
+            if !close {
+                return Err(QuantorError::NotAllEqual { kind: QuantorKind::AllClose, index: i + 1 });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// A bound on the number of matching elements, accepted by [`exactly_n`].
+///
+/// Implemented for an exact `usize` count and for the ranges `a..=b`, `a..` and `..b`, so exact and
+/// range cardinality checks share one function. The reported
+/// [`ExactlyNFailed`](QuantorError::ExactlyNFailed) error names the bound that was violated:
+///
+/// | Bound  | Too few matches                  | Too many matches                    |
+/// |--------|----------------------------------|-------------------------------------|
+/// | `n`    | `Comparison::Exactly`, `n`       | `Comparison::Exactly`, `n`          |
+/// | `a..=b`| `Comparison::AtLeast`, `a`       | `Comparison::AtMost`, `b`           |
+/// | `a..`  | `Comparison::AtLeast`, `a`       | -                                   |
+/// | `..b`  | -                                | `Comparison::AtMost`, `b - 1`       |
+///
+/// ## Panics
+/// The range implementations panic if the range is empty, such as `..0` or `5..=2`, since no count
+/// could satisfy it and the error would contradict itself.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::{exactly_n, CountBound}, error::Comparison};
+///
+/// assert!((2..=5).admits(3));
+/// assert_eq!((2..=5).violation(7), Some((Comparison::AtMost, 5)));
+/// assert_eq!(4.violation(4), None);
+///
+/// assert!(exactly_n(&[1, 2, 3], 1.., |x| x % 2 == 1).is_ok());
+/// ```
+/// ```should_panic(expected = "count range must not be empty")
+/// use quantor::quantifiers::exactly_n;
+///
+/// let _ = exactly_n(&[1, 2, 3], ..0, |x| x % 2 == 1);
+/// ```
+pub trait CountBound {
+    /// Returns the comparison and expected count that `found` violates, or `None` if it is admitted.
+    fn violation(&self, found: usize) -> Option<(Comparison, usize)>;
+
+    /// Returns `true` if `found` matches satisfy the bound.
+    #[inline]
+    fn admits(&self, found: usize) -> bool {
+        self.violation(found).is_none()
+    }
+}
+
+impl CountBound for usize {
+    #[inline]
+    fn violation(&self, found: usize) -> Option<(Comparison, usize)> {
+        (found != *self).then_some((Comparison::Exactly, *self))
+    }
+}
+
+impl CountBound for RangeInclusive<usize> {
+    #[inline]
+    fn violation(&self, found: usize) -> Option<(Comparison, usize)> {
+        assert!(!self.is_empty(), "count range must not be empty");
+
+        if found < *self.start() {
+            Some((Comparison::AtLeast, *self.start()))
+        } else if found > *self.end() {
+            Some((Comparison::AtMost, *self.end()))
+        } else {
+            None
+        }
+    }
+}
+
+impl CountBound for RangeFrom<usize> {
+    #[inline]
+    fn violation(&self, found: usize) -> Option<(Comparison, usize)> {
+        (found < self.start).then_some((Comparison::AtLeast, self.start))
+    }
+}
+
+impl CountBound for RangeTo<usize> {
+    #[inline]
+    fn violation(&self, found: usize) -> Option<(Comparison, usize)> {
+        assert!(self.end > 0, "count range must not be empty");
+
+        (found >= self.end).then_some((Comparison::AtMost, self.end - 1))
+    }
+}
+
+/// Checks if the number of elements satisfying the predicate is within `n`.
+///
+/// Equivalent to **_|{x ∈ iter | pred(x)}| ∈ n_**, where `n` is either an exact count or a range
+/// (see [`CountBound`]).
+///
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `n` - The exact number of elements assumed to satisfy `pred`, or a range `a..=b`, `a..` or `..b`.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if the number of matches satisfies `n`.
+/// - `Err(QuantorError::ExactlyNFailed { kind, found, expected, comparison })` otherwise.
+/// ## Panics
+/// Panics if `n` is an empty range, such as `..0`.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::exactly_n;
+/// use quantor::error::QuantorResultExt;
+///
+/// let values = vec![1, 2, 4, 6];
+/// let result = exactly_n(&values, 3, |x| x % 2 == 0);
+///
+/// assert!(result.is_ok());
+///
+/// let err = exactly_n(&values, 2, |x| x % 2 == 0);
+///
+/// if let Some(expected) = err.match_count() {
+///     assert_eq!(expected, 3);
+/// }
+/// ```
+///
+/// Ranges bound the count from one or both sides:
+/// ```
+/// use quantor::{quantifiers::exactly_n, error::QuantorResultExt};
+///
+/// let values = vec![1, 2, 4, 6];
+///
+/// assert!(exactly_n(&values, 2..=5, |x| x % 2 == 0).is_ok());
+/// assert!(exactly_n(&values, 3.., |x| x % 2 == 0).is_ok());
+/// assert!(exactly_n(&values, ..2, |x| x % 2 == 1).is_ok());
+///
+/// let err = exactly_n(&values, 0..=1, |x| x % 2 == 0).unwrap_err();
+/// assert_eq!(err.to_string(), "Expected at most 1 elements to match, found 3 for quantifier exactly_n.");
+///
+/// let err = exactly_n(&values, 4.., |x| x % 2 == 0).unwrap_err();
+/// assert_eq!(err.to_string(), "Expected at least 4 elements to match, found 3 for quantifier exactly_n.");
+///
+/// let err = exactly_n(&values, ..3, |x| x % 2 == 0);
+/// assert_eq!(err.expected_count(), Some(2));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn exactly_n<'a, I, T: 'a, N, F>(
+    iter: I,
+    n: N,
+    mut pred: F,
+) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    N: CountBound,
+    F: FnMut(&T) -> bool,
+{
+    let found = iter.into_iter().filter(|x| pred(x)).count();
+
+    match n.violation(found) {
+        None => Ok(()),
+        Some((comparison, expected)) => Err(QuantorError::ExactlyNFailed { kind: QuantorKind::ExactlyN, found, expected, comparison }),
+    }
+}
+
+/// Checks if at least `n` elements in the iterator satisfy the predicate.
+///
+/// Equivalent to **_|{x ∈ iter | pred(x)}| ≥ n_**
+///
+/// Stops as soon as `n` matches have been found.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `n` - The minimum number of elements to satisfy `pred`.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if at least `n` elements match.
+/// - `Err(QuantorError::ExactlyNFailed { kind, found, expected, comparison: Comparison::AtLeast })` otherwise.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::at_least;
+///
+/// let values = vec![1, 2, 4, 6];
+///
+/// assert!(at_least(&values, 2, |x| x % 2 == 0).is_ok());
+///
+/// let err = at_least(&values, 4, |x| x % 2 == 0).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "Expected at least 4 elements to match, found 3 for quantifier at_least.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn at_least<'a, I, T: 'a, F>(
+    iter: I,
+    n: usize,
+    mut pred: F,
+) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    if n == 0 {
+        return Ok(());
+    }
+
+    let mut found = 0;
+
+    for item in iter {
+        if pred(item) {
+            found += 1;
+            if found >= n {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(QuantorError::ExactlyNFailed { kind: QuantorKind::AtLeast, found, expected: n, comparison: Comparison::AtLeast })
+}
+
+/// Checks if at most `n` elements in the iterator satisfy the predicate.
+///
+/// Equivalent to **_|{x ∈ iter | pred(x)}| ≤ n_**
+///
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `n` - The maximum number of elements to satisfy `pred`.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if at most `n` elements match.
+/// - `Err(QuantorError::ExactlyNFailed { kind, found, expected, comparison: Comparison::AtMost })` otherwise.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::at_most;
+///
+/// let values = vec![1, 2, 4, 6];
+///
+/// assert!(at_most(&values, 3, |x| x % 2 == 0).is_ok());
+///
+/// let err = at_most(&values, 1, |x| x % 2 == 0).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "Expected at most 1 elements to match, found 3 for quantifier at_most.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn at_most<'a, I, T: 'a, F>(
+    iter: I,
+    n: usize,
+    mut pred: F,
+) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let found = iter.into_iter().filter(|x| pred(x)).count();
+
+    if found <= n {
+        Ok(())
+    } else {
+        Err(QuantorError::ExactlyNFailed { kind: QuantorKind::AtMost, found, expected: n, comparison: Comparison::AtMost })
+    }
+}
+
+/// Checks if all elements satisfy the predicate, treating empty input as a failure.
+///
+/// Equivalent to **_iter ≠ ∅ ∧ ∀a ∈ iter: pred(a)_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if the collection is non-empty and all elements satisfy the predicate.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::PredicateFailed { kind, index })` if an element fails the predicate, with the index of the first failure.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_nonempty, QuantorError};
+///
+/// let empty: Vec<i32> = vec!();
+/// let numbers = vec!(0, 2, 4);
+///
+/// assert!(forall_nonempty(&numbers, |x| x % 2 == 0).is_ok());
+/// assert!(matches!(forall_nonempty(&empty, |x| x % 2 == 0), Err(QuantorError::EmptyInput { .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_nonempty<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter().peekable();
+
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput { kind: QuantorKind::Forall });
+    }
+
+    forall(iter, pred)
+}
+
+/// Checks if no element satisfies the predicate, treating empty input as a failure.
+///
+/// Equivalent to **_iter ≠ ∅ ∧ ∀a ∈ iter: ¬pred(a)_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if the collection is non-empty and no elements satisfy the predicate.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` if at least one element satisfies the predicate, with the `index`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::none_nonempty, QuantorError};
+///
+/// let empty: Vec<i32> = vec!();
+/// let numbers = vec!(1, 3, 5);
+///
+/// assert!(none_nonempty(&numbers, |x| x % 2 == 0).is_ok());
+/// assert!(matches!(none_nonempty(&empty, |x| x % 2 == 0), Err(QuantorError::EmptyInput { .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn none_nonempty<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter().peekable();
+
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput { kind: QuantorKind::None });
+    }
+
+    none(iter, pred)
+}
+
+/// Checks if all elements are equal to each other, treating empty input as a failure.
+///
+/// Equivalent to **_iter ≠ ∅ ∧ ∀a,b ∈ iter: a = b_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// ## Returns
+/// - `Ok(())` if the collection is non-empty and all elements are equal to each other.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::NotAllEqual { kind, index })` if an element at `index` is not equal to the first element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::all_equal_nonempty, QuantorError};
+///
+/// let empty: Vec<i32> = vec!();
+/// let ones = vec!(1, 1, 1);
+///
+/// assert!(all_equal_nonempty(&ones).is_ok());
+/// assert!(matches!(all_equal_nonempty(&empty), Err(QuantorError::EmptyInput { .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn all_equal_nonempty<'a, I, T>(iter: I) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq
+{
+    let mut iter = iter.into_iter().peekable();
+
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput { kind: QuantorKind::AllEqual });
+    }
+
+    all_equal(iter)
+}
+
+/// Checks whether a binary predicate holds for all adjacent pairs, treating empty input as a failure.
+///
+/// Equivalent to **_iter ≠ ∅ ∧ ∀(aᵢ, aᵢ₊₁) ∈ iter: pred(aᵢ, aᵢ₊₁)_**.
+///
+/// **Note**: _A single element has no adjacent pairs and therefore still succeeds._
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each adjacent pair against.
+/// ## Returns
+/// - `Ok(())` if the collection is non-empty and the predicate holds for all adjacent pairs.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` if any pair violates the predicate.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::pairwise_nonempty, QuantorError};
+///
+/// let empty: Vec<i32> = vec!();
+/// let numbers = vec!(0, 1, 2);
+///
+/// assert!(pairwise_nonempty(&numbers, |a, b| a < b).is_ok());
+/// assert!(matches!(pairwise_nonempty(&empty, |a, b| a < b), Err(QuantorError::EmptyInput { .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn pairwise_nonempty<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut iter = iter.into_iter().peekable();
+
+    if iter.peek().is_none() {
+        return Err(QuantorError::EmptyInput { kind: QuantorKind::Pairwise });
+    }
+
+    pairwise(iter, pred)
 }
\ No newline at end of file
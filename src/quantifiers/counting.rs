@@ -0,0 +1,155 @@
+//! Counting quantifiers that operate directly on iterators.
+//!
+//! Unlike [`exactly_n`](crate::quantifiers::basic::exactly_n), which counts every match, these
+//! functions stop consuming the iterator as soon as the outcome is decided. This makes them suitable
+//! for lazy, expensive, or potentially infinite iterators, and for iterators yielding owned values.
+//!
+//! It also provides [`counter`], the frequency distribution of a collection, along with lightweight
+//! statistics derived from it such as [`distinct_count`] and [`shannon_entropy`].
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{error::{Comparison, QuantorKind}, QuantorError};
+
+/// Checks if exactly `n` elements of the iterator satisfy the predicate, stopping at `n + 1` matches.
+///
+/// Equivalent to **_|{x ∈ iter | pred(x)}| = n_**.
+///
+/// **Note**: _On failure with too many matches, `found` is reported as `n + 1`, since the remaining
+/// elements are never visited. An infinite iterator with at most `n` matches never terminates._
+/// ## Arguments
+/// - `iter` - The iterator to be checked. Its items are passed to the predicate by reference.
+/// - `n` - The number of elements expected to satisfy `pred`.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - `Ok(())` if exactly `n` elements match.
+/// - `Err(QuantorError::ExactlyNFailed { kind, found, expected, comparison })` otherwise.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::count_exactly, error::QuantorResultExt};
+///
+/// assert!(count_exactly(1..=10, 5, |x| x % 2 == 0).is_ok());
+///
+/// // Stops after the fourth even number, even though the range is unbounded.
+/// let result = count_exactly(1.., 3, |x| x % 2 == 0);
+/// assert_eq!(result.match_count(), Some(4));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn count_exactly<I, F>(iter: I, n: usize, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    let found = iter.into_iter().filter(|x| pred(x)).take(n.saturating_add(1)).count();
+
+    if found == n {
+        Ok(())
+    } else {
+        Err(QuantorError::ExactlyNFailed { kind: QuantorKind::ExactlyN, found, expected: n, comparison: Comparison::Exactly })
+    }
+}
+
+/// Counts how often each distinct element occurs.
+///
+/// Equivalent to **_{(x, count(x)) | x ∈ iter}_**.
+/// ## Arguments
+/// - `iter` - The collection to inspect.
+/// ## Returns
+/// - A `HashMap<&T, usize>` mapping each distinct element to its number of occurrences.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::counter;
+///
+/// let words = vec!["a", "b", "a", "c", "a"];
+/// let counts = counter(&words);
+///
+/// assert_eq!(counts[&"a"], 3);
+/// assert_eq!(counts[&"b"], 1);
+/// assert_eq!(counts.len(), 3);
+/// ```
+#[inline]
+#[must_use]
+pub fn counter<'a, I, T>(iter: I) -> HashMap<&'a T, usize>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq + Hash,
+{
+    let mut counts = HashMap::new();
+
+    for item in iter {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+/// Counts the number of distinct elements.
+///
+/// Equivalent to **_|{x ∈ iter}|_**.
+/// ## Arguments
+/// - `iter` - The collection to inspect.
+/// ## Returns
+/// - The number of distinct elements, or `0` for empty input.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::distinct_count;
+///
+/// assert_eq!(distinct_count(&[1, 2, 2, 3, 3, 3]), 3);
+/// assert_eq!(distinct_count(&Vec::<i32>::new()), 0);
+/// ```
+#[inline]
+#[must_use]
+pub fn distinct_count<'a, I, T>(iter: I) -> usize
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq + Hash,
+{
+    counter(iter).len()
+}
+
+/// Computes the Shannon entropy of the element distribution, in bits.
+///
+/// Equivalent to **_−Σ p(x) · log₂ p(x)_**, where **_p(x) = count(x) / |iter|_**.
+///
+/// The entropy is `0.0` when all elements are equal, and `log₂(k)` when `k` distinct elements
+/// occur equally often, which makes it a convenient measure of how skewed a column of data is.
+/// ## Arguments
+/// - `iter` - The collection to inspect.
+/// ## Returns
+/// - The entropy in bits, or `0.0` for empty input.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::shannon_entropy;
+///
+/// let uniform = vec!['a', 'b', 'c', 'd'];
+/// let skewed  = vec!['a', 'a', 'a', 'b'];
+/// let single  = vec!['a', 'a', 'a', 'a'];
+///
+/// assert!((shannon_entropy(&uniform) - 2.0).abs() < 1e-12);
+/// assert!((shannon_entropy(&skewed) - 0.811_278_124_459_132_8).abs() < 1e-12);
+/// assert_eq!(shannon_entropy(&single), 0.0);
+/// ```
+#[inline]
+#[must_use]
+pub fn shannon_entropy<'a, I, T>(iter: I) -> f64
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq + Hash,
+{
+    let counts = counter(iter);
+    let total: usize = counts.values().sum();
+
+    if total == 0 {
+        return 0.0;
+    }
+
+    let total = total as f64;
+    counts
+        .values()
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
@@ -0,0 +1,41 @@
+//! Small guard functions used as preconditions before running quantifiers.
+//!
+//! These do not evaluate predicates themselves, but validate basic properties of the input.
+
+use crate::{error::QuantorKind, QuantorError};
+
+/// Checks that the collection is not empty and returns its number of elements.
+///
+/// Equivalent to **_iter ≠ ∅_**, returning **_|iter|_**.
+///
+/// The input is consumed to count its elements via `Iterator::count`, which is O(1) for slice
+/// and `Vec` iterators.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// ## Returns
+/// - `Ok(len)` with the number of elements if the collection is non-empty.
+/// - `Err(QuantorError::EmptyInput { kind: QuantorKind::Custom })` if the collection is empty.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::require_nonempty;
+///
+/// let rows = vec!["a", "b", "c"];
+/// let empty: Vec<&str> = vec!();
+///
+/// assert_eq!(require_nonempty(&rows), Ok(3));
+/// assert!(require_nonempty(&empty).unwrap_err().is_empty_input());
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn require_nonempty<I>(iter: I) -> Result<usize, QuantorError>
+where
+    I: IntoIterator,
+{
+    let len = iter.into_iter().count();
+
+    if len == 0 {
+        Err(QuantorError::EmptyInput { kind: QuantorKind::Custom })
+    } else {
+        Ok(len)
+    }
+}
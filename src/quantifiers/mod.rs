@@ -6,18 +6,35 @@
 //! The module is split into logical categories:
 //!
 //! - [`core`] – Fundamental quantifiers such as `forall`, `exists`, and `none`.
+//! - [`counting`] – Short-circuiting counting quantifiers such as `count_exactly`, and frequency statistics like `counter`.
 //! - [`nested`] – Nested quantifier constructs such as `forallexists` and `existsforall`.
 //! - [`selection`] – Selection utilities based on predicates, like `select_where` or `select_unique`.
+//! - [`strings`] – Character-wise quantifiers over `&str`, such as `forall_char` and `exists_char`.
 //! - [`structured`] – Structure-oriented logic, such as pairwise conditions or equality across elements.
+//! - [`update`] – Single-pass checks that update elements in place, such as `for_each_checked`.
+//! - [`helpers`] – Precondition guards such as `require_nonempty`.
 //!
 //! These tools work well with the predicates module and assertion macros to support logical validation and filtering.
 
 pub mod basic;
+pub mod counting;
+pub mod helpers;
 pub mod nested;
 pub mod selection;
+pub mod strings;
 pub mod structured;
+pub mod update;
 
 pub use basic::*;
+pub use counting::*;
+pub use helpers::*;
 pub use nested::*;
 pub use selection::*;
+pub use strings::*;
 pub use structured::*;
+pub use update::*;
+
+// Shadows the glob re-export above, so the limit is only reachable as `structured::MISMATCH_LIMIT`,
+// like `error::ERRORS_DISPLAY_LIMIT`.
+#[allow(unused_imports, hidden_glob_reexports)]
+use structured::MISMATCH_LIMIT;
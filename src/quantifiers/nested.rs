@@ -2,23 +2,27 @@
 //!
 //! These are helpful for modeling containment, dominance, or existential constraints in rule engines.
 
-use crate::{error::QuantorKind, QuantorError};
+use std::{collections::HashSet, hash::Hash};
+
+use crate::{error::{QuantorKind, Side}, QuantorError};
 
 /// Checks whether for every element in `a`, there exists at least one element in `b` for which the predicate holds.
 /// 
 /// Equivalent to **_∀x ∈ a ∃y ∈ b: pred(x, y)_**.
 /// 
 /// **Note**: _If `b` is empty and `a` is not, this will always return an error._
+/// If `a` is empty, the check succeeds vacuously.
 /// ## Arguments
 /// - `a` - The source collection (outer quantifier).
 /// - `b` - The comparison collection.
 /// - `pred` - The binary predicate to check against.
 /// ## Returns
 /// - `Ok(())` if every element in the left-hand collection (`a`) satisfies the condition against at least one element in the right-hand collection (`b`).
+/// - `Err(QuantorError::NestedEmptyInput { kind, side: Side::Right })` if `b` is empty and `a` is not.
 /// - `Err(QuantorError::ForAllExistsFailed { kind, outer_index })` if any `a` fails to match.
 /// ## Example
 /// ```
-/// use quantor::{quantifiers::forallexists, error::QuantorResultExt};
+/// use quantor::{quantifiers::forallexists, error::{QuantorResultExt, Side}, QuantorError};
 ///
 /// let a = vec![1, 2];
 /// let b = vec![2, 3, 4];
@@ -31,19 +35,26 @@ use crate::{error::QuantorKind, QuantorError};
 /// if let Some(index) = err.failing_index() {
 ///     assert_eq!(0, index);
 /// }
+///
+/// let empty: Vec<i32> = vec![];
+/// assert!(matches!(forallexists(&a, &empty, |x, y| x < y), Err(QuantorError::NestedEmptyInput { side: Side::Right, .. })));
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
 pub fn forallexists<'a, A: 'a, B: 'a>(
     a: impl IntoIterator<Item = &'a A>,
     b: impl IntoIterator<Item = &'a B>,
-    pred: impl Fn(&A, &B) -> bool,
+    mut pred: impl FnMut(&A, &B) -> bool,
 ) -> Result<(), QuantorError> {
 
     let b_vec: Vec<&'a B> = b.into_iter().collect();
     let mut matched;
 
     for (outer_index, item_a) in a.into_iter().enumerate() {
+        if b_vec.is_empty() {
+            return Err(QuantorError::NestedEmptyInput { kind: QuantorKind::ForAllExists, side: Side::Right });
+        }
+
         matched = false;
 
         for item_b in &b_vec {
@@ -64,12 +75,16 @@ pub fn forallexists<'a, A: 'a, B: 'a>(
 /// Checks whether there exists an element in `a` for which all elements in `b` satisfy the predicate.
 /// 
 /// Equivalent to **_∃x ∈ a ∀y ∈ b: pred(x, y)_**.
+///
+/// If `a` is empty, there is no candidate and the check fails. If `b` is empty, any element of `a`
+/// satisfies the condition vacuously.
 /// ## Arguments
 /// - `a` - The source collection (outer quantifier).
 /// - `b` - The comparison collection.
 /// - `pred` - The binary predicate to check against.
 /// ## Returns
 /// - `Ok(())` if there exists at least one element in the left-hand collection (`a`) such that the condition holds against all elements in the right-hand collection (`b`).
+/// - `Err(QuantorError::NestedEmptyInput { kind, side: Side::Left })` if `a` is empty.
 /// - `Err(QuantorError::ExistsForAllFailed { kind, outer_index })` if no such element exists, where `outer_index` is the index of the first failing `a` element.
 /// ## Example
 /// ```
@@ -92,7 +107,7 @@ pub fn forallexists<'a, A: 'a, B: 'a>(
 pub fn existsforall<'a, A: 'a, B: 'a>(
     a: impl IntoIterator<Item = &'a A>,
     b: impl IntoIterator<Item = &'a B>,
-    pred: impl Fn(&A, &B) -> bool,
+    mut pred: impl FnMut(&A, &B) -> bool,
 ) -> Result<(), QuantorError> {
 
     let b_vec: Vec<&'a B> = b.into_iter().collect();
@@ -117,5 +132,275 @@ pub fn existsforall<'a, A: 'a, B: 'a>(
         }
     }
 
-    Err(QuantorError::ExistsForAllFailed { kind: QuantorKind::ExistsForAll, outer_index: first_index.unwrap_or(0) })
+    match first_index {
+        Some(outer_index) => Err(QuantorError::ExistsForAllFailed { kind: QuantorKind::ExistsForAll, outer_index }),
+        None => Err(QuantorError::NestedEmptyInput { kind: QuantorKind::ExistsForAll, side: Side::Left }),
+    }
+}
+
+/// Checks whether the predicate holds for every pair of elements from `a` and `b`.
+///
+/// Equivalent to **_∀x ∈ a ∀y ∈ b: pred(x, y)_**.
+///
+/// If either collection is empty, there are no pairs and the check succeeds vacuously.
+/// ## Arguments
+/// - `a` - The left-hand collection.
+/// - `b` - The right-hand collection.
+/// - `pred` - The binary predicate to check against.
+/// ## Returns
+/// - `Ok(())` if every pair satisfies the predicate.
+/// - `Err(QuantorError::CrossPairFailed { kind, left_index, right_index })` for the first failing pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forallforall, QuantorError};
+///
+/// let low = vec![1, 2];
+/// let high = vec![5, 2, 9];
+///
+/// assert!(forallforall(&low, &high, |x, y| x <= y).is_ok());
+/// assert!(matches!(
+///     forallforall(&low, &high, |x, y| x < y),
+///     Err(QuantorError::CrossPairFailed { left_index: 1, right_index: 1, .. })
+/// ));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forallforall<'a, A: 'a, B: 'a>(
+    a: impl IntoIterator<Item = &'a A>,
+    b: impl IntoIterator<Item = &'a B>,
+    mut pred: impl FnMut(&A, &B) -> bool,
+) -> Result<(), QuantorError> {
+    let b_vec: Vec<&'a B> = b.into_iter().collect();
+
+    for (left_index, item_a) in a.into_iter().enumerate() {
+        for (right_index, item_b) in b_vec.iter().enumerate() {
+            if !pred(item_a, item_b) {
+                return Err(QuantorError::CrossPairFailed { kind: QuantorKind::ForallForall, left_index, right_index });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether the predicate holds for at least one pair of elements from `a` and `b`.
+///
+/// Equivalent to **_∃x ∈ a ∃y ∈ b: pred(x, y)_**.
+///
+/// If either collection is empty, there are no pairs and the check fails.
+/// ## Arguments
+/// - `a` - The left-hand collection.
+/// - `b` - The right-hand collection.
+/// - `pred` - The binary predicate to check against.
+/// ## Returns
+/// - `Ok(())` if some pair satisfies the predicate.
+/// - `Err(QuantorError::NestedEmptyInput { kind, side })` if a collection is empty. `side` is
+///   [`Side::Left`] whenever `a` is empty, and [`Side::Right`] if only `b` is.
+/// - `Err(QuantorError::NoMatch { kind })` if no pair does.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::existsexists, error::{QuantorKind, Side}, QuantorError};
+///
+/// let a = vec![1, 4];
+/// let b = vec![3, 8];
+///
+/// assert!(existsexists(&a, &b, |x, y| x * 2 == *y).is_ok());
+/// assert!(existsexists(&a, &b, |x, y| x == y).unwrap_err().is_no_match());
+///
+/// let empty: Vec<i32> = vec![];
+/// assert_eq!(
+///     existsexists(&a, &empty, |x, y| x == y),
+///     Err(QuantorError::NestedEmptyInput { kind: QuantorKind::ExistsExists, side: Side::Right })
+/// );
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn existsexists<'a, A: 'a, B: 'a>(
+    a: impl IntoIterator<Item = &'a A>,
+    b: impl IntoIterator<Item = &'a B>,
+    mut pred: impl FnMut(&A, &B) -> bool,
+) -> Result<(), QuantorError> {
+    let b_vec: Vec<&'a B> = b.into_iter().collect();
+    let mut a_empty = true;
+
+    for item_a in a {
+        a_empty = false;
+        if b_vec.iter().any(|item_b| pred(item_a, item_b)) {
+            return Ok(());
+        }
+    }
+
+    if a_empty {
+        Err(QuantorError::NestedEmptyInput { kind: QuantorKind::ExistsExists, side: Side::Left })
+    } else if b_vec.is_empty() {
+        Err(QuantorError::NestedEmptyInput { kind: QuantorKind::ExistsExists, side: Side::Right })
+    } else {
+        Err(QuantorError::NoMatch { kind: QuantorKind::ExistsExists })
+    }
+}
+
+/// Checks whether every element in `a` also appears in `b`.
+///
+/// Equivalent to **_a ⊆ b_**, i.e. **_∀x ∈ a ∃y ∈ b: x = y_**.
+///
+/// Unlike `forallexists(a, b, |x, y| x == y)`, this hashes `b` once and runs in `O(|a| + |b|)`.
+/// ## Arguments
+/// - `a` - The collection expected to be the subset.
+/// - `b` - The collection expected to be the superset.
+/// ## Returns
+/// - `Ok(())` if every element of `a` is contained in `b`.
+/// - `Err(QuantorError::ForAllExistsFailed { kind, outer_index })` with the index of the first element of `a` missing from `b`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::is_subset, error::QuantorResultExt};
+///
+/// let required = vec!["id", "name"];
+/// let columns  = vec!["id", "name", "email"];
+///
+/// assert!(is_subset(&required, &columns).is_ok());
+///
+/// let err = is_subset(&columns, &required);
+///
+/// assert_eq!(err.failing_index(), Some(2));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn is_subset<'a, T: 'a + Eq + Hash>(
+    a: impl IntoIterator<Item = &'a T>,
+    b: impl IntoIterator<Item = &'a T>,
+) -> Result<(), QuantorError> {
+    contained_in(a, b, QuantorKind::Subset)
+}
+
+/// Checks whether every element in `b` also appears in `a`.
+///
+/// Equivalent to **_a ⊇ b_**, i.e. **_∀y ∈ b ∃x ∈ a: x = y_**.
+///
+/// This hashes `a` once and runs in `O(|a| + |b|)`.
+/// ## Arguments
+/// - `a` - The collection expected to be the superset.
+/// - `b` - The collection expected to be the subset.
+/// ## Returns
+/// - `Ok(())` if every element of `b` is contained in `a`.
+/// - `Err(QuantorError::ForAllExistsFailed { kind, outer_index })` with the index of the first element of `b` missing from `a`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::is_superset, error::QuantorResultExt};
+///
+/// let columns  = vec!["id", "name", "email"];
+/// let required = vec!["id", "phone"];
+///
+/// let err = is_superset(&columns, &required);
+///
+/// assert_eq!(err.failing_index(), Some(1));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn is_superset<'a, T: 'a + Eq + Hash>(
+    a: impl IntoIterator<Item = &'a T>,
+    b: impl IntoIterator<Item = &'a T>,
+) -> Result<(), QuantorError> {
+    contained_in(b, a, QuantorKind::Superset)
+}
+
+/// Checks whether `a` and `b` share no elements.
+///
+/// Equivalent to **_a ∩ b = ∅_**, i.e. **_∀x ∈ a ∀y ∈ b: x ≠ y_**.
+///
+/// This hashes `b` once and runs in `O(|a| + |b|)`.
+/// ## Arguments
+/// - `a` - The source collection.
+/// - `b` - The collection that must not contain any element of `a`.
+/// ## Returns
+/// - `Ok(())` if no element of `a` appears in `b`.
+/// - `Err(QuantorError::UnexpectedMatch { kind, index })` with the index of the first element of `a` found in `b`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::disjoint, error::QuantorResultExt};
+///
+/// let allowed = vec![80, 443];
+/// let blocked = vec![22, 23];
+///
+/// assert!(disjoint(&allowed, &blocked).is_ok());
+///
+/// let overlapping = vec![8080, 22];
+/// let err = disjoint(&overlapping, &blocked);
+///
+/// assert_eq!(err.failing_index(), Some(1));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn disjoint<'a, T: 'a + Eq + Hash>(
+    a: impl IntoIterator<Item = &'a T>,
+    b: impl IntoIterator<Item = &'a T>,
+) -> Result<(), QuantorError> {
+    let b_set: HashSet<&'a T> = b.into_iter().collect();
+
+    for (index, item) in a.into_iter().enumerate() {
+        if b_set.contains(item) {
+            return Err(QuantorError::UnexpectedMatch { kind: QuantorKind::Disjoint, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether a predicate holds for every element of every inner collection.
+///
+/// Equivalent to **_∀c ∈ outer ∀x ∈ c: pred(x)_**.
+///
+/// Unlike flattening the input first, the error keeps both the position of the inner collection
+/// and the position of the failing element within it.
+/// ## Arguments
+/// - `outer` - The collection of inner collections.
+/// - `pred` - The predicate to test each inner element against.
+/// ## Returns
+/// - `Ok(())` if every inner element satisfies the predicate.
+/// - `Err(QuantorError::NestedPredicateFailed { kind, outer_index, inner_index })` for the first failing element.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_nested, QuantorError};
+///
+/// let grid = vec![vec![1, 2], vec![3, 4, -5]];
+///
+/// assert!(forall_nested(&grid, |x| *x != 0).is_ok());
+/// assert!(matches!(
+///     forall_nested(&grid, |x| *x > 0),
+///     Err(QuantorError::NestedPredicateFailed { outer_index: 1, inner_index: 2, .. })
+/// ));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_nested<'a, C: 'a, T: 'a>(
+    outer: impl IntoIterator<Item = &'a C>,
+    mut pred: impl FnMut(&T) -> bool,
+) -> Result<(), QuantorError>
+where
+    &'a C: IntoIterator<Item = &'a T>,
+{
+    for (outer_index, inner) in outer.into_iter().enumerate() {
+        for (inner_index, item) in inner.into_iter().enumerate() {
+            if !pred(item) {
+                return Err(QuantorError::NestedPredicateFailed { kind: QuantorKind::ForallNested, outer_index, inner_index });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn contained_in<'a, T: 'a + Eq + Hash>(
+    items: impl IntoIterator<Item = &'a T>,
+    container: impl IntoIterator<Item = &'a T>,
+    kind: QuantorKind,
+) -> Result<(), QuantorError> {
+    let container: HashSet<&'a T> = container.into_iter().collect();
+
+    for (outer_index, item) in items.into_iter().enumerate() {
+        if !container.contains(item) {
+            return Err(QuantorError::ForAllExistsFailed { kind, outer_index });
+        }
+    }
+
+    Ok(())
 }
\ No newline at end of file
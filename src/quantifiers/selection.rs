@@ -2,11 +2,14 @@
 //! 
 //! Useful for applications such as enforcing uniquenes or extracting anomalies based on logical rules.
 
-use std::{collections::{HashMap, HashSet}, hash::Hash};
+use std::{cmp::{Ordering, Reverse}, collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet}, hash::Hash};
 
 /// Selects elements that satisfy the predicate.
 /// 
 /// Equivalent to **_{x ∈ self | pred(x)}_**.
+///
+/// If the input reports an exact size (e.g. slices and vectors), the result reserves room for every
+/// element up front, which avoids reallocations when most elements match.
 /// ## Arguments
 /// - `iter` - The collection to filter.
 /// - `pred` - The predicate to use for filtering.
@@ -22,12 +25,252 @@ use std::{collections::{HashMap, HashSet}, hash::Hash};
 /// ```
 #[inline]
 #[must_use]
-pub fn select_where<'a, I, T: 'a, F>(iter: I, pred: F) -> Vec<&'a T>
+pub fn select_where<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Vec<&'a T>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
-    iter.into_iter().filter(|x| pred(x)).collect()
+    let iter = iter.into_iter();
+    let mut result = match iter.size_hint() {
+        (lower, Some(upper)) if lower == upper => Vec::with_capacity(lower),
+        _ => Vec::new(),
+    };
+
+    for item in iter {
+        if pred(item) {
+            result.push(item);
+        }
+    }
+
+    result
+}
+
+/// Selects elements that satisfy the predicate, evaluating it in parallel.
+///
+/// Equivalent to **_{x ∈ self | pred(x)}_**.
+///
+/// Produces the same result as [`select_where`]. For ordered parallel iterators, such as those of
+/// slices and `Vec`, matches are returned in input order, since rayon's `collect` into a `Vec`
+/// preserves it. Unordered sources such as a `HashSet` yield their matches in no particular order.
+/// The predicate may run on any thread and in any order, so it must be `Fn` rather than `FnMut`.
+///
+/// Requires the `rayon` feature.
+/// ## Arguments
+/// - `iter` - The parallel collection to filter, e.g. a slice or `&Vec<T>`.
+/// - `pred` - The predicate to use for filtering.
+/// ## Returns
+/// - A `Vec<&T>` containing all matching elements, in input order for ordered sources.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::{par_select_where, select_where};
+///
+/// let numbers: Vec<u32> = (0..10_000).collect();
+///
+/// let parallel = par_select_where(&numbers, |x| x % 7 == 3);
+///
+/// assert_eq!(parallel, select_where(&numbers, |x| x % 7 == 3));
+/// assert_eq!(parallel[..3], [&3, &10, &17]);
+/// ```
+#[cfg(feature = "rayon")]
+#[inline]
+#[must_use]
+pub fn par_select_where<'a, I, T, F>(iter: I, pred: F) -> Vec<&'a T>
+where
+    I: rayon::iter::IntoParallelIterator<Item = &'a T>,
+    T: 'a + Sync,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    use rayon::iter::ParallelIterator;
+
+    iter.into_par_iter().filter(|item| pred(item)).collect()
+}
+
+/// Selects elements that do not satisfy the predicate.
+///
+/// Equivalent to **_{x ∈ self | ¬pred(x)}_**.
+///
+/// This is the selection counterpart of [`failing_elements`](crate::quantifiers::structured::failing_elements),
+/// to which it delegates.
+/// ## Arguments
+/// - `iter` - The collection to filter.
+/// - `pred` - The predicate to use for filtering.
+/// ## Returns
+/// - A `Vec<&T>` containing all non-matching elements.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_where_not;
+///
+/// let numbers = vec!(0, 1, 2, 3);
+///
+/// assert_eq!(select_where_not(&numbers, |x| x % 2 == 0), vec!(&1, &3));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_where_not<'a, I, T: 'a, F>(iter: I, pred: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    crate::quantifiers::structured::failing_elements(iter, pred)
+}
+
+/// Selects elements for which the predicate holds, given both their position and value.
+///
+/// Equivalent to **_{xᵢ ∈ self | pred(i, xᵢ)}_**.
+/// ## Arguments
+/// - `iter` - The collection to filter.
+/// - `pred` - The predicate receiving the index and the element.
+/// ## Returns
+/// - A `Vec<&T>` containing all matching elements, in order.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_where_pos;
+///
+/// let numbers = vec!(3, -1, -4, 1, 5, 9);
+///
+/// // Positive elements at even positions.
+/// assert_eq!(select_where_pos(&numbers, |i, x| i % 2 == 0 && *x > 0), vec!(&3, &5));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_where_pos<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(usize, &T) -> bool,
+{
+    iter.into_iter()
+        .enumerate()
+        .filter(|(i, item)| pred(*i, item))
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// Selects the positions of elements that satisfy the predicate.
+///
+/// Equivalent to **_{i | pred(xᵢ)}_**.
+///
+/// Unlike [`select_where`], the result holds no references into the input, so it can outlive a
+/// borrow and be used to index back into the original data later.
+/// ## Arguments
+/// - `iter` - The collection to scan.
+/// - `pred` - The predicate to use for filtering.
+/// ## Returns
+/// - A `Vec<usize>` with the index of every matching element, in ascending order.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::indices_where;
+///
+/// let numbers = vec!(1, 4, 7, 8, 9);
+///
+/// assert_eq!(indices_where(&numbers, |x| x % 2 == 0), vec!(1, 3));
+/// ```
+#[inline]
+#[must_use]
+pub fn indices_where<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    iter.into_iter()
+        .enumerate()
+        .filter(|(_, item)| pred(item))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Selects the maximal runs of consecutive elements that all satisfy the predicate.
+///
+/// Equivalent to **_{(i, [xᵢ, …, xⱼ]) | pred(xₖ) ∀k ∈ [i, j], ¬pred(xᵢ₋₁), ¬pred(xⱼ₊₁)}_**.
+///
+/// Unlike [`select_where`], which flattens all matches into one list, each run is returned
+/// separately together with the index of its first element.
+/// ## Arguments
+/// - `iter` - The collection to scan.
+/// - `pred` - The predicate every element of a run must satisfy.
+/// ## Returns
+/// - A `Vec<(usize, Vec<&T>)>` with the start index and elements of each run, in input order.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_runs_where;
+///
+/// let status = vec!(200, 500, 503, 200, 200, 500);
+///
+/// assert_eq!(
+///     select_runs_where(&status, |s| *s >= 500),
+///     vec!((1, vec!(&500, &503)), (5, vec!(&500))),
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn select_runs_where<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Vec<(usize, Vec<&'a T>)>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut runs: Vec<(usize, Vec<&'a T>)> = Vec::new();
+    let mut in_run = false;
+
+    for (index, item) in iter.into_iter().enumerate() {
+        if !pred(item) {
+            in_run = false;
+            continue;
+        }
+
+        match runs.last_mut() {
+            Some((_, run)) if in_run => run.push(item),
+            _ => runs.push((index, vec![item])),
+        }
+        in_run = true;
+    }
+
+    runs
+}
+
+/// Selects the first `n` elements that satisfy the predicate.
+///
+/// Equivalent to **_take(n, {x ∈ self | pred(x)})_**.
+///
+/// Stops consuming the input as soon as `n` matches have been collected, so the predicate is not
+/// evaluated on the remaining elements.
+/// ## Arguments
+/// - `iter` - The collection to filter.
+/// - `n` - The maximum number of matches to collect.
+/// - `pred` - The predicate to use for filtering.
+/// ## Returns
+/// - A `Vec<&T>` containing at most `n` matching elements, in input order.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_first_n;
+///
+/// let numbers = vec!(0, 1, 2, 3, 4, 5);
+/// let mut checked = 0;
+///
+/// assert_eq!(select_first_n(&numbers, 2, |x| { checked += 1; x % 2 == 0 }), vec!(&0, &2));
+/// assert_eq!(checked, 3);
+/// ```
+#[inline]
+#[must_use]
+pub fn select_first_n<'a, I, T: 'a, F>(iter: I, n: usize, mut pred: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut result = Vec::new();
+
+    for item in iter {
+        if pred(item) {
+            result.push(item);
+            if result.len() == n {
+                break;
+            }
+        }
+    }
+
+    result
 }
 
 /// Selects elements that satisfy the predicate and returns only unique matches.
@@ -48,16 +291,17 @@ where
 /// ```
 #[inline]
 #[must_use]
-pub fn select_unique<'a, I, T, F>(iter: I, pred: F) -> Vec<&'a T>
+pub fn select_unique<'a, I, T, F>(iter: I, mut pred: F) -> Vec<&'a T>
 where
     I: IntoIterator<Item = &'a T>,
     T: 'a + Eq + Hash,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
     let mut uniques = HashSet::new();
     let mut result  = Vec::new();
 
     for item in iter {
+        #[allow(clippy::collapsible_if)]
         if pred(item) {
             if !uniques.contains(item) { // Avoids redundant insertion/check.
                 uniques.insert(item);
@@ -75,7 +319,8 @@ where
 /// ## Arguments
 /// - `iter` - The collection to filter.
 /// ## Returns
-/// - A `Vec<&T>` containing all duplicates found.
+/// - A `Vec<&T>` with the first occurrence of each duplicated element, ordered by first occurrence.
+///   The order is deterministic, so the result can be used in snapshot tests.
 /// ## Example
 /// ```
 /// use quantor::quantifiers::select_duplicates;
@@ -83,19 +328,390 @@ where
 /// let numbers = vec!(0, 1, 2, 2, 3);
 /// 
 /// assert_eq!(select_duplicates(&numbers), vec!(&2));
+/// assert_eq!(select_duplicates(&[3, 1, 3, 1, 2]), vec!(&3, &1));
 /// ```
 #[inline]
 #[must_use]
 pub fn select_duplicates<'a, I, T>(iter: I) -> Vec<&'a T>
 where
     I: IntoIterator<Item = &'a T>,
-    T: 'a + Eq + Hash + Clone,
+    T: 'a + Eq + Hash,
+{
+    duplicate_counts(iter).into_iter().map(|(item, _)| item).collect()
+}
+
+/// Counts how often each duplicated element occurs.
+///
+/// Equivalent to **_{(x, count(x)) | x ∈ self, count(x) > 1}_**.
+/// ## Arguments
+/// - `iter` - The collection to inspect.
+/// ## Returns
+/// - A `Vec<(&T, usize)>` with each duplicated element and its number of occurrences,
+///   ordered by the element's first occurrence.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::duplicate_counts;
+///
+/// let ids = vec!(7, 3, 7, 5, 3, 7);
+///
+/// assert_eq!(duplicate_counts(&ids), vec!((&7, 3), (&3, 2)));
+/// ```
+#[inline]
+#[must_use]
+pub fn duplicate_counts<'a, I, T>(iter: I) -> Vec<(&'a T, usize)>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Eq + Hash,
+{
+    let mut positions: HashMap<&'a T, usize> = HashMap::new();
+    let mut counts: Vec<(&'a T, usize)> = Vec::new();
+
+    for item in iter {
+        match positions.get(item) {
+            Some(&position) => counts[position].1 += 1,
+            None => {
+                positions.insert(item, counts.len());
+                counts.push((item, 1));
+            }
+        }
+    }
+
+    counts.retain(|(_, count)| *count > 1);
+    counts
+}
+
+/// Selects one element for every key that occurs more than once.
+///
+/// Equivalent to **_{x ∈ self | count(key(x)) > 1}_**, keeping only the first element per key.
+///
+/// The key function receives references that live as long as the input, so it can return
+/// borrowed fields such as `&user.email` without cloning.
+/// ## Arguments
+/// - `iter` - The collection to filter.
+/// - `key` - The projection used to detect duplicates.
+/// ## Returns
+/// - A `Vec<&T>` with the first element of each duplicated key, ordered by first occurrence.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_duplicates_by;
+///
+/// struct User { name: &'static str, email: String }
+///
+/// let users = vec!(
+///     User { name: "ann", email: "a@x.io".into() },
+///     User { name: "bob", email: "b@x.io".into() },
+///     User { name: "amy", email: "a@x.io".into() },
+/// );
+///
+/// let dups = select_duplicates_by(&users, |u| &u.email);
+///
+/// assert_eq!(dups.len(), 1);
+/// assert_eq!(dups[0].name, "ann");
+/// ```
+#[inline]
+#[must_use]
+pub fn select_duplicates_by<'a, I, T: 'a, K, F>(iter: I, mut key: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: Eq + Hash,
+    F: FnMut(&'a T) -> K,
 {
-    let mut counts = HashMap::new();
-    
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut counts: Vec<(&'a T, usize)> = Vec::new();
+
     for item in iter {
-        *counts.entry(item).or_insert(0) += 1;
+        match positions.entry(key(item)) {
+            Entry::Occupied(entry) => counts[*entry.get()].1 += 1,
+            Entry::Vacant(entry) => {
+                entry.insert(counts.len());
+                counts.push((item, 1));
+            }
+        }
     }
 
     counts.into_iter().filter(|(_, count)| *count > 1).map(|(item, _)| item).collect()
-}
\ No newline at end of file
+}
+
+/// Selects the `n` elements with the largest key.
+///
+/// Equivalent to **_top_n({key(x) | x ∈ self})_**.
+///
+/// Uses a bounded heap of size `n`, so the cost is `O(len · log n)` rather than sorting the whole input.
+/// Elements with equal keys are ranked by first occurrence.
+/// ## Arguments
+/// - `iter` - The collection to select from.
+/// - `n` - The maximum number of elements to select.
+/// - `key` - The projection used to rank each element.
+/// ## Returns
+/// - A `Vec<&T>` of at most `n` elements, sorted by descending key.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_top_n;
+///
+/// let scores = vec!(40, 90, 10, 70, 90);
+///
+/// assert_eq!(select_top_n(&scores, 3, |x| *x), vec!(&90, &90, &70));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_top_n<'a, I, T: 'a, K, F>(iter: I, n: usize, mut key: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    select_best_n(iter, n, |index, item| (key(item), Reverse(index)))
+}
+
+/// Selects the `n` elements with the smallest key.
+///
+/// Equivalent to **_bottom_n({key(x) | x ∈ self})_**.
+///
+/// Uses a bounded heap of size `n`, so the cost is `O(len · log n)` rather than sorting the whole input.
+/// Elements with equal keys are ranked by first occurrence.
+/// ## Arguments
+/// - `iter` - The collection to select from.
+/// - `n` - The maximum number of elements to select.
+/// - `key` - The projection used to rank each element.
+/// ## Returns
+/// - A `Vec<&T>` of at most `n` elements, sorted by ascending key.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_bottom_n;
+///
+/// let scores = vec!(40, 90, 10, 70, 90);
+///
+/// assert_eq!(select_bottom_n(&scores, 2, |x| *x), vec!(&10, &40));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_bottom_n<'a, I, T: 'a, K, F>(iter: I, n: usize, mut key: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    select_best_n(iter, n, |index, item| (Reverse(key(item)), Reverse(index)))
+}
+
+/// Selects the element with the smallest key.
+///
+/// Equivalent to **_argmin({key(x) | x ∈ self})_**.
+///
+/// Elements with equal keys are ranked by first occurrence. Keys that are not comparable to
+/// themselves (such as `f64::NAN`) are skipped.
+/// ## Arguments
+/// - `iter` - The collection to select from.
+/// - `key` - The projection used to rank each element.
+/// ## Returns
+/// - `Some(&T)` with the first element whose key is minimal.
+/// - `None` if the collection is empty or no key is comparable.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_min_by;
+///
+/// let temps = vec!(("mon", 12.5), ("tue", f64::NAN), ("wed", 9.0), ("thu", 9.0));
+///
+/// assert_eq!(select_min_by(&temps, |t| t.1), Some(&("wed", 9.0)));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_min_by<'a, I, T: 'a, K, F>(iter: I, key: F) -> Option<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    select_extremum_by(iter, Ordering::Less, key)
+}
+
+/// Selects the element with the largest key.
+///
+/// Equivalent to **_argmax({key(x) | x ∈ self})_**.
+///
+/// Elements with equal keys are ranked by first occurrence. Keys that are not comparable to
+/// themselves (such as `f64::NAN`) are skipped.
+/// ## Arguments
+/// - `iter` - The collection to select from.
+/// - `key` - The projection used to rank each element.
+/// ## Returns
+/// - `Some(&T)` with the first element whose key is maximal.
+/// - `None` if the collection is empty or no key is comparable.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::select_max_by;
+///
+/// let words = vec!("kiwi", "banana", "cherry");
+///
+/// assert_eq!(select_max_by(&words, |w| w.len()), Some(&"banana"));
+/// ```
+#[inline]
+#[must_use]
+pub fn select_max_by<'a, I, T: 'a, K, F>(iter: I, key: F) -> Option<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    select_extremum_by(iter, Ordering::Greater, key)
+}
+
+/// Returns the first element whose key orders as `wanted` against every other comparable key.
+fn select_extremum_by<'a, I, T: 'a, K, F>(iter: I, wanted: Ordering, mut key: F) -> Option<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    let mut best: Option<(&'a T, K)> = None;
+
+    for item in iter {
+        let k = key(item);
+        if k.partial_cmp(&k).is_none() {
+            continue;
+        }
+
+        match &best {
+            Some((_, best_key)) if k.partial_cmp(best_key) != Some(wanted) => {}
+            _ => best = Some((item, k)),
+        }
+    }
+
+    best.map(|(item, _)| item)
+}
+
+/// An element paired with its rank, ordered by rank only.
+struct Ranked<'a, T, R> {
+    rank: R,
+    item: &'a T,
+}
+
+impl<T, R: Ord> PartialEq for Ranked<'_, T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl<T, R: Ord> Eq for Ranked<'_, T, R> {}
+
+impl<T, R: Ord> PartialOrd for Ranked<'_, T, R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, R: Ord> Ord for Ranked<'_, T, R> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+/// Keeps the `n` highest-ranked elements in a min-heap and returns them sorted best first.
+fn select_best_n<'a, I, T: 'a, R, F>(iter: I, n: usize, mut rank: F) -> Vec<&'a T>
+where
+    I: IntoIterator<Item = &'a T>,
+    R: Ord,
+    F: FnMut(usize, &T) -> R,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut heap = BinaryHeap::new();
+
+    for (index, item) in iter.into_iter().enumerate() {
+        let candidate = Ranked { rank: rank(index, item), item };
+
+        if heap.len() < n {
+            heap.push(Reverse(candidate));
+        } else if let Some(mut worst) = heap.peek_mut() {
+            if candidate > worst.0 {
+                *worst = Reverse(candidate);
+            }
+        }
+    }
+
+    let mut best: Vec<_> = heap.into_iter().map(|Reverse(ranked)| ranked).collect();
+    best.sort_by(|a, b| b.cmp(a));
+    best.into_iter().map(|ranked| ranked.item).collect()
+}
+
+/// A lazy, chainable selection over a collection.
+///
+/// Composes the steps of [`select_where`], [`select_unique`] and [`select_top_n`] into a single
+/// pipeline without materializing a `Vec` between steps. Nothing is evaluated until
+/// [`collect`](Selection::collect) is called; predicates and keys then run in input order as the
+/// pipeline is drained.
+///
+/// [`top_n`](Selection::top_n) has to see every remaining element before yielding, so it buffers
+/// its result once evaluation starts. All other steps stream.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::Selection;
+///
+/// let scores = vec!(40, 90, 15, 70, 90, 55, 70);
+///
+/// let best_passing = Selection::new(&scores)
+///     .where_(|x| *x >= 50)
+///     .unique()
+///     .top_n(2, |x| *x)
+///     .collect();
+///
+/// assert_eq!(best_passing, vec!(&90, &70));
+/// ```
+pub struct Selection<'a, T> {
+    iter: Box<dyn Iterator<Item = &'a T> + 'a>,
+}
+
+impl<'a, T: 'a> Selection<'a, T> {
+    /// Starts a selection over the given collection.
+    #[inline]
+    #[must_use]
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = &'a T>,
+        I::IntoIter: 'a,
+    {
+        Selection { iter: Box::new(iter.into_iter()) }
+    }
+
+    /// Keeps the elements that satisfy the predicate, like [`select_where`].
+    #[inline]
+    #[must_use]
+    pub fn where_<F>(self, mut pred: F) -> Self
+    where
+        F: FnMut(&T) -> bool + 'a,
+    {
+        Selection { iter: Box::new(self.iter.filter(move |item| pred(item))) }
+    }
+
+    /// Keeps the first occurrence of every distinct element, like [`select_unique`].
+    #[inline]
+    #[must_use]
+    pub fn unique(self) -> Self
+    where
+        T: Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        Selection { iter: Box::new(self.iter.filter(move |item| seen.insert(*item))) }
+    }
+
+    /// Keeps the `n` elements with the largest key, sorted by descending key, like [`select_top_n`].
+    #[inline]
+    #[must_use]
+    pub fn top_n<K, F>(self, n: usize, mut key: F) -> Self
+    where
+        K: Ord,
+        F: FnMut(&T) -> K + 'a,
+    {
+        let iter = std::iter::once(self.iter).flat_map(move |iter| select_top_n(iter, n, &mut key));
+        Selection { iter: Box::new(iter) }
+    }
+
+    /// Evaluates the pipeline and returns the selected elements.
+    #[inline]
+    #[must_use]
+    pub fn collect(self) -> Vec<&'a T> {
+        self.iter.collect()
+    }
+}
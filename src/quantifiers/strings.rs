@@ -0,0 +1,78 @@
+//! Quantifiers over the characters of a string slice.
+//!
+//! A `&str` is not an `IntoIterator<Item = &char>`, so the generic quantifiers cannot be applied to
+//! it directly. These variants iterate its `char`s and are handy for validating identifiers,
+//! passwords, codes and similar text.
+//!
+//! ## Indices
+//! Reported indices are **char indices**, i.e. the position in `s.chars()`, not byte offsets. They
+//! only coincide for ASCII text. To slice the original string at a failure, convert the index with
+//! `s.char_indices().nth(index)`.
+
+use crate::{error::QuantorKind, QuantorError};
+
+/// Checks if every character of the string satisfies the predicate.
+///
+/// Equivalent to **_∀c ∈ chars(s): pred(c)_**.
+/// An empty string returns `Ok(())`, in line with [`forall`](crate::quantifiers::basic::forall).
+/// ## Arguments
+/// - `s` - The string to be checked.
+/// - `pred` - The predicate to test each character against.
+/// ## Returns
+/// - `Ok(())` if all characters satisfy the predicate.
+/// - `Err(QuantorError::PredicateFailed { kind, index })` for the first failing character, with its char index.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::forall_char, error::QuantorResultExt};
+///
+/// assert!(forall_char("user_42", |c| c.is_ascii_alphanumeric() || c == '_').is_ok());
+///
+/// // 'ü' is two bytes long, but the reported index counts characters.
+/// assert_eq!(forall_char("grüße!", char::is_alphabetic).failing_index(), Some(5));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_char<F>(s: &str, mut pred: F) -> Result<(), QuantorError>
+where
+    F: FnMut(char) -> bool,
+{
+    for (index, c) in s.chars().enumerate() {
+        if !pred(c) {
+            return Err(QuantorError::PredicateFailed { kind: QuantorKind::Forall, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks if at least one character of the string satisfies the predicate.
+///
+/// Equivalent to **_∃c ∈ chars(s): pred(c)_**.
+/// An empty string returns an error, in line with [`exists`](crate::quantifiers::basic::exists).
+/// ## Arguments
+/// - `s` - The string to be checked.
+/// - `pred` - The predicate to test each character against.
+/// ## Returns
+/// - `Ok(())` if at least one character satisfies the predicate.
+/// - `Err(QuantorError::NoMatch { kind })` if no character does.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::exists_char;
+///
+/// let password = "hunter2";
+///
+/// assert!(exists_char(password, |c| c.is_ascii_digit()).is_ok());
+/// assert!(exists_char(password, |c| c.is_ascii_uppercase()).unwrap_err().is_no_match());
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn exists_char<F>(s: &str, pred: F) -> Result<(), QuantorError>
+where
+    F: FnMut(char) -> bool,
+{
+    if s.chars().any(pred) {
+        Ok(())
+    } else {
+        Err(QuantorError::NoMatch { kind: QuantorKind::Exists })
+    }
+}
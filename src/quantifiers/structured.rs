@@ -2,7 +2,9 @@
 //!
 //! These functions are useful in areas such as reporting or constrained filtering.
 
-use crate::{error::QuantorKind, QuantorError};
+use std::{cmp::Ordering, fmt::Display, ops::Sub};
+
+use crate::{error::{Mismatch, QuantorKind}, QuantorError, QuantorErrors};
 
 /// Checks whether a binary predicate holds for all adjacent pairs.
 /// 
@@ -23,10 +25,10 @@ use crate::{error::QuantorKind, QuantorError};
 /// ```
 #[inline]
 #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-pub fn pairwise<'a, I, T: 'a, F>(iter: I, pred: F) -> Result<(), QuantorError>
+pub fn pairwise<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T, &T) -> bool,
+    F: FnMut(&T, &T) -> bool,
 {
     let mut iter = iter.into_iter();
     let mut prev = match iter.next() {
@@ -36,6 +38,7 @@ where
 
     let mut index = 0;
 
+    #[allow(clippy::explicit_counter_loop)]
     for curr in iter {
         if !pred(prev, curr) {
             // Index `i` here refers to the second item in the failing pair.
@@ -47,6 +50,262 @@ where
 
     Ok(())
 }
+
+/// Checks whether a binary predicate holds for all adjacent pairs, reporting every pair that violates it.
+///
+/// Equivalent to: **∀(aᵢ, aᵢ₊₁) ∈ self: pred(aᵢ, aᵢ₊₁)**.
+/// Unlike [`pairwise`], this does not stop at the first violation but checks every pair.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each pair against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for all adjacent pairs.
+/// - `Err(QuantorErrors)` with one `QuantorError::PairwiseFailed { kind, index }` per failing pair, in order.
+///   The `index` refers to the first element of the pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::pairwise_collect, error::QuantorResultExt};
+///
+/// let numbers = vec![1, 3, 2, 4, 0];
+///
+/// assert_eq!(pairwise_collect(&numbers, |a, b| a < b).failing_indices(), vec![1, 3]);
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn pairwise_collect<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorErrors>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let mut errors = QuantorErrors::new();
+
+    if let Some(mut prev) = iter.next() {
+        for (index, curr) in iter.enumerate() {
+            if !pred(prev, curr) {
+                errors.push(QuantorError::PairwiseFailed { kind: QuantorKind::Pairwise, index });
+            }
+            prev = curr;
+        }
+    }
+
+    errors.into_result()
+}
+
+/// Checks whether a ternary predicate holds for all triples of adjacent elements.
+///
+/// Equivalent to **_∀(aᵢ, aᵢ₊₁, aᵢ₊₂) ∈ self: pred(aᵢ, aᵢ₊₁, aᵢ₊₂)_**.
+///
+/// A dedicated form of [`windowed`] with a window size of three that keeps the last two elements
+/// as it slides instead of collecting the input, so it does not allocate. Collections with fewer
+/// than three elements contain no triples, and the check succeeds vacuously.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each triple against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for all adjacent triples.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` if any triple violates the predicate,
+///   where `index` is the position of the first element of the triple.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::triplewise, error::QuantorResultExt};
+///
+/// let no_local_max = |a: &i32, b: &i32, c: &i32| !(b > a && b > c);
+///
+/// assert!(triplewise(&[1, 2, 3, 3, 5], no_local_max).is_ok());
+///
+/// let err = triplewise(&[1, 2, 3, 1, 5], no_local_max);
+///
+/// assert_eq!(err.failing_index(), Some(1));
+/// assert_eq!(err.unwrap_err().to_string(), "Predicate failed for window starting at index 1 for quantifier windowed.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn triplewise<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T, &T, &T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let (mut first, mut second) = match (iter.next(), iter.next()) {
+        (Some(a), Some(b)) => (a, b),
+        _ => return Ok(())
+    };
+
+    for (index, third) in iter.enumerate() {
+        if !pred(first, second, third) {
+            // `index` here refers to the first item in the failing triple.
+            return Err(QuantorError::PairwiseFailed { kind: QuantorKind::Windowed, index });
+        }
+        first = second;
+        second = third;
+    }
+
+    Ok(())
+}
+
+/// Checks whether a binary predicate holds for all adjacent pairs of differing elements.
+///
+/// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ ≠ aᵢ₊₁ → pred(aᵢ, aᵢ₊₁)_**.
+///
+/// Adjacent pairs with `aᵢ == aᵢ₊₁` are skipped without calling the predicate, so repeated values
+/// are tolerated. Every other adjacent pair is checked, which makes this equivalent to running
+/// `pairwise` after collapsing runs of equal elements.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each pair of differing adjacent elements against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for all adjacent pairs of differing elements.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` if any such pair violates the predicate,
+///   where `index` is the position of the first element of the pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::pairwise_distinct, error::QuantorResultExt};
+///
+/// // A state log alternating between two states, with repeated readings.
+/// let states = vec!["on", "on", "off", "off", "off", "on", "on", "on"];
+/// assert!(pairwise_distinct(&states, |a, b| a != b).is_ok());
+///
+/// let readings = vec![1, 1, 2, 2, 1];
+/// let result = pairwise_distinct(&readings, |a, b| a < b);
+/// assert_eq!(result.failing_index(), Some(3));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn pairwise_distinct<'a, I, T, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + PartialEq,
+    F: FnMut(&T, &T) -> bool,
+{
+    pairwise(iter, |a, b| a == b || pred(a, b))
+}
+
+/// Checks whether a predicate holds for the difference of every adjacent pair.
+///
+/// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: pred(aᵢ₊₁ - aᵢ)_**.
+///
+/// Requires `T: Sub<Output = T> + Copy`, so the difference is computed on copies of the elements.
+/// Subtraction follows the semantics of `T`, so integer underflow panics in debug builds; use a
+/// signed or floating-point type when the sequence may decrease.
+/// ## Arguments
+/// - `iter` - The numeric sequence to be checked.
+/// - `pred` - The predicate to test each difference `aᵢ₊₁ - aᵢ` against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for every difference. Sequences with fewer than two elements always pass.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` for the first failing pair, where `index`
+///   is the position of the first element of the pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::pairwise_delta, error::QuantorResultExt};
+///
+/// let timestamps = vec![100, 105, 112, 118];
+/// assert!(pairwise_delta(&timestamps, |d| *d > 0 && *d <= 10).is_ok());
+///
+/// let readings = vec![1.0, 1.5, 4.0, 4.2];
+/// assert_eq!(pairwise_delta(&readings, |d: &f64| d.abs() < 1.0).failing_index(), Some(1));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn pairwise_delta<'a, I, T, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Sub<Output = T> + Copy,
+    F: FnMut(&T) -> bool,
+{
+    pairwise(iter, |a, b| pred(&(*b - *a)))
+}
+
+/// Returns the differences between adjacent elements.
+///
+/// Equivalent to **_(aᵢ₊₁ - aᵢ) for i in 0..n-1_**.
+///
+/// Requires `T: Sub<Output = T> + Copy`, so the difference is computed on copies of the elements.
+/// ## Arguments
+/// - `iter` - The numeric sequence.
+/// ## Returns
+/// - A `Vec<T>` with one difference per adjacent pair, i.e. one element fewer than the input.
+///   Empty if the input has fewer than two elements.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::deltas;
+///
+/// assert_eq!(deltas(&[1, 4, 9, 16]), vec![3, 5, 7]);
+/// assert!(deltas(&[42]).is_empty());
+/// ```
+#[inline]
+#[must_use]
+pub fn deltas<'a, I, T>(iter: I) -> Vec<T>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + Sub<Output = T> + Copy,
+{
+    let mut iter = iter.into_iter();
+    let mut result = Vec::with_capacity(iter.size_hint().0.saturating_sub(1));
+
+    if let Some(mut prev) = iter.next() {
+        for curr in iter {
+            result.push(*curr - *prev);
+            prev = curr;
+        }
+    }
+
+    result
+}
+
+/// Checks whether every element is strictly greater than its predecessor.
+///
+/// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ < aᵢ₊₁_**.
+///
+/// Unlike `pairwise(iter, |a, b| a < b)`, the error tells apart a repeated value from a drop in
+/// the sequence, which helps when validating ID or index lists.
+/// ## Arguments
+/// - `iter` - The sequence to be checked.
+/// ## Returns
+/// - `Ok(())` if the sequence is strictly increasing. Sequences with fewer than two elements always are.
+/// - `Err(QuantorError::DuplicateAt { kind, index })` if an element equals its predecessor.
+/// - `Err(QuantorError::DecreaseAt { kind, index })` if an element is smaller than, or incomparable to, its predecessor.
+///
+/// In both error cases, `index` is the position of the later element of the offending pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::strictly_increasing, QuantorError};
+///
+/// assert!(strictly_increasing(&[1, 4, 9]).is_ok());
+///
+/// let err = strictly_increasing(&[1, 4, 4, 9]).unwrap_err();
+/// assert!(matches!(err, QuantorError::DuplicateAt { index: 2, .. }));
+/// assert_eq!(err.to_string(), "Duplicate at index 2 for quantifier strictly_increasing.");
+///
+/// let err = strictly_increasing(&[1, 4, 3]).unwrap_err();
+/// assert_eq!(err.to_string(), "Decrease at index 2 for quantifier strictly_increasing.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn strictly_increasing<'a, I, T>(iter: I) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + PartialOrd,
+{
+    let mut iter = iter.into_iter();
+    let mut prev = match iter.next() {
+        Some(p) => p,
+        None => return Ok(())
+    };
+
+    for (offset, curr) in iter.enumerate() {
+        let index = offset + 1;
+        match prev.partial_cmp(curr) {
+            Some(Ordering::Less) => {},
+            Some(Ordering::Equal) => return Err(QuantorError::DuplicateAt { kind: QuantorKind::StrictlyIncreasing, index }),
+            _ => return Err(QuantorError::DecreaseAt { kind: QuantorKind::StrictlyIncreasing, index }),
+        }
+        prev = curr;
+    }
+
+    Ok(())
+}
+
 /// Gets all elements that fail the predicate.
 /// 
 /// Equivalent to **_{x ∈ self | ¬pred(x)}_**.
@@ -65,10 +324,459 @@ where
 /// ```
 #[inline]
 #[must_use = "Use this to inspect failing elements from a quantifier."]
-pub fn failing_elements<'a, I, T: 'a, F>(iter: I, pred: F) -> Vec<&'a T>
+pub fn failing_elements<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Vec<&'a T>
 where
     I: IntoIterator<Item = &'a T>,
-    F: Fn(&T) -> bool,
+    F: FnMut(&T) -> bool,
 {
     iter.into_iter().filter(|x| !pred(x)).collect()
-}
\ No newline at end of file
+}
+
+/// Counts how often the predicate changes its result between adjacent elements.
+///
+/// Equivalent to **_|{(aᵢ, aᵢ₊₁) ∈ self | pred(aᵢ) ≠ pred(aᵢ₊₁)}|_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate describing the state of each element.
+/// ## Returns
+/// - The number of adjacent pairs whose elements differ in their predicate result.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::count_transitions;
+///
+/// let is_open = vec!(false, true, true, false, true);
+///
+/// assert_eq!(count_transitions(&is_open, |x| *x), 3);
+/// ```
+#[inline]
+#[must_use]
+pub fn count_transitions<'a, I, T: 'a, F>(iter: I, mut pred: F) -> usize
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let mut prev = match iter.next() {
+        Some(p) => pred(p),
+        None => return 0
+    };
+
+    let mut count = 0;
+
+    for curr in iter {
+        let state = pred(curr);
+        if state != prev {
+            count += 1;
+        }
+        prev = state;
+    }
+
+    count
+}
+
+/// Checks whether the predicate changes its result between adjacent elements at most `n` times.
+///
+/// Equivalent to **_|{(aᵢ, aᵢ₊₁) ∈ self | pred(aᵢ) ≠ pred(aᵢ₊₁)}| ≤ n_**.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `n` - The maximum number of allowed transitions.
+/// - `pred` - The predicate describing the state of each element.
+/// ## Returns
+/// - `Ok(())` if there are at most `n` transitions.
+/// - `Err(QuantorError::TransitionLimitExceeded { kind, index, limit })` on the first transition exceeding the limit,
+///   where `index` is the index of the first element in the transitioning pair.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::transitions_at_most, error::QuantorResultExt};
+///
+/// let is_open = vec!(false, true, true, false, true);
+///
+/// assert!(transitions_at_most(&is_open, 3, |x| *x).is_ok());
+///
+/// let err = transitions_at_most(&is_open, 2, |x| *x);
+///
+/// assert_eq!(err.failing_index(), Some(3));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn transitions_at_most<'a, I, T: 'a, F>(iter: I, n: usize, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let mut prev = match iter.next() {
+        Some(p) => pred(p),
+        None => return Ok(())
+    };
+
+    let mut count = 0;
+
+    for (index, curr) in iter.enumerate() {
+        let state = pred(curr);
+        if state != prev {
+            count += 1;
+            if count > n {
+                return Err(QuantorError::TransitionLimitExceeded { kind: QuantorKind::Transitions, index, limit: n });
+            }
+        }
+        prev = state;
+    }
+
+    Ok(())
+}
+
+/// Collects each maximal run of equal adjacent elements together with its length.
+///
+/// Equivalent to a run-length encoding of `iter`.
+/// ## Arguments
+/// - `iter` - The collection to be encoded.
+/// ## Returns
+/// - A `Vec<(&T, usize)>` with the first element of each run and the number of elements in it.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::runs;
+///
+/// let numbers = vec!(1, 1, 2, 3, 3, 3);
+///
+/// assert_eq!(runs(&numbers), vec!((&1, 2), (&2, 1), (&3, 3)));
+/// ```
+#[inline]
+#[must_use]
+pub fn runs<'a, I, T>(iter: I) -> Vec<(&'a T, usize)>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: 'a + PartialEq,
+{
+    let mut result: Vec<(&'a T, usize)> = Vec::new();
+
+    for item in iter {
+        match result.last_mut() {
+            Some((value, len)) if *value == item => *len += 1,
+            _ => result.push((item, 1)),
+        }
+    }
+
+    result
+}
+
+/// Groups maximal runs of adjacent elements that share the same projected key.
+///
+/// Equivalent to splitting the sequence wherever **_key(aᵢ) ≠ key(aᵢ₊₁)_**.
+///
+/// Unlike [`runs`], which compares the elements themselves, elements are grouped by a computed
+/// key. Non-adjacent elements with equal keys end up in separate groups.
+/// ## Arguments
+/// - `iter` - The sequence to be segmented.
+/// - `key` - The projection used to compare adjacent elements.
+/// ## Returns
+/// - A `Vec<Vec<&T>>` of non-empty groups in their original order. Empty if the input is empty.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::group_consecutive_by;
+///
+/// let log = vec!(("INFO", 1), ("INFO", 2), ("WARN", 3), ("INFO", 4));
+///
+/// let groups = group_consecutive_by(&log, |entry| entry.0);
+///
+/// assert_eq!(groups.len(), 3);
+/// assert_eq!(groups[0], vec!(&("INFO", 1), &("INFO", 2)));
+/// assert_eq!(groups[2], vec!(&("INFO", 4)));
+/// ```
+#[inline]
+#[must_use]
+pub fn group_consecutive_by<'a, I, T: 'a, K, F>(iter: I, mut key: F) -> Vec<Vec<&'a T>>
+where
+    I: IntoIterator<Item = &'a T>,
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    let mut groups: Vec<Vec<&'a T>> = Vec::new();
+    let mut current_key: Option<K> = None;
+
+    for item in iter {
+        let k = key(item);
+
+        match (groups.last_mut(), &current_key) {
+            (Some(group), Some(prev)) if *prev == k => group.push(item),
+            _ => groups.push(vec![item]),
+        }
+
+        current_key = Some(k);
+    }
+
+    groups
+}
+
+/// Checks whether a binary predicate holds for the first and last element.
+///
+/// Equivalent to **_pred(a₀, aₙ₋₁)_**.
+///
+/// For a single-element collection, that element is passed as both the first and the last.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate receiving the first and the last element.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for the endpoints.
+/// - `Err(QuantorError::EmptyInput { kind })` if the collection is empty.
+/// - `Err(QuantorError::PredicateFailed { kind, index: 0 })` if the predicate fails.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::endpoints, QuantorError};
+///
+/// let numbers = vec!(1, 4, 2, 9);
+///
+/// assert!(endpoints(&numbers, |first, last| first < last).is_ok());
+/// assert!(endpoints(&numbers, |first, last| first > last).is_err());
+///
+/// let empty: Vec<i32> = vec!();
+/// assert!(matches!(endpoints(&empty, |a, b| a < b), Err(QuantorError::EmptyInput { .. })));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn endpoints<'a, I, T: 'a, F>(iter: I, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let first = match iter.next() {
+        Some(f) => f,
+        None => return Err(QuantorError::EmptyInput { kind: QuantorKind::Endpoints }),
+    };
+    let last = iter.last().unwrap_or(first);
+
+    if pred(first, last) {
+        Ok(())
+    } else {
+        Err(QuantorError::PredicateFailed { kind: QuantorKind::Endpoints, index: 0 })
+    }
+}
+
+/// Checks whether a predicate holds for every sliding window of `size` adjacent elements.
+///
+/// Equivalent to **_∀i: pred(aᵢ, …, aᵢ₊ₙ₋₁)_** with `n = size`.
+///
+/// The predicate receives each window as a `&[&T]` slice of length `size`. If the collection has
+/// fewer than `size` elements there are no windows, and the check succeeds vacuously.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `size` - The number of elements in each window.
+/// - `pred` - The predicate to test each window against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for all windows.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` with the index of the first element of the first failing window.
+/// ## Panics
+/// Panics if `size` is `0`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::windowed, error::QuantorResultExt};
+///
+/// let numbers = vec!(1, 2, 3, 4, 5);
+///
+/// assert!(windowed(&numbers, 3, |w| w.iter().copied().sum::<i32>() < 13).is_ok());
+///
+/// let err = windowed(&numbers, 3, |w| w.iter().copied().sum::<i32>() < 9);
+///
+/// assert_eq!(err.failing_index(), Some(1));
+/// assert_eq!(err.unwrap_err().to_string(), "Predicate failed for window starting at index 1 for quantifier windowed.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn windowed<'a, I, T: 'a, F>(iter: I, size: usize, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&[&'a T]) -> bool,
+{
+    assert!(size > 0, "window size must be non-zero");
+
+    let items: Vec<&'a T> = iter.into_iter().collect();
+
+    for (index, window) in items.windows(size).enumerate() {
+        if !pred(window) {
+            return Err(QuantorError::PairwiseFailed { kind: QuantorKind::Windowed, index });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether a predicate holds for every non-overlapping chunk of `size` elements.
+///
+/// Equivalent to **_∀k: pred(aₖₙ, …, aₖₙ₊ₙ₋₁)_** with `n = size`.
+///
+/// The predicate receives each chunk as a `&[&T]` slice. The last chunk is shorter than `size`
+/// if the number of elements is not a multiple of `size`.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `size` - The number of elements in each chunk.
+/// - `pred` - The predicate to test each chunk against.
+/// ## Returns
+/// - `Ok(())` if the predicate holds for all chunks.
+/// - `Err(QuantorError::PairwiseFailed { kind, index })` with the index of the first element of the first failing chunk.
+/// ## Panics
+/// Panics if `size` is `0`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::chunks_forall, error::QuantorResultExt};
+///
+/// let pairs = vec!(1, -1, 2, -2, 3, 3);
+///
+/// assert!(chunks_forall(&pairs[..4], 2, |c| c.iter().copied().sum::<i32>() == 0).is_ok());
+///
+/// let err = chunks_forall(&pairs, 2, |c| c.iter().copied().sum::<i32>() == 0);
+///
+/// assert_eq!(err.failing_index(), Some(4));
+/// assert_eq!(err.unwrap_err().to_string(), "Predicate failed for chunk starting at index 4 for quantifier chunks_forall.");
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn chunks_forall<'a, I, T: 'a, F>(iter: I, size: usize, mut pred: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&[&'a T]) -> bool,
+{
+    assert!(size > 0, "chunk size must be non-zero");
+
+    let items: Vec<&'a T> = iter.into_iter().collect();
+
+    for (chunk_index, chunk) in items.chunks(size).enumerate() {
+        if !pred(chunk) {
+            return Err(QuantorError::PairwiseFailed { kind: QuantorKind::Chunked, index: chunk_index * size });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether two sequences are element-wise equal.
+///
+/// Equivalent to **_|actual| = |expected| ∧ ∀i: actualᵢ = expectedᵢ_**.
+///
+/// Useful for comparing output against a known ("golden") sequence.
+/// ## Arguments
+/// - `actual` - The sequence to be checked.
+/// - `expected` - The sequence it should equal.
+/// ## Returns
+/// - `Ok(())` if both sequences have the same length and equal elements.
+/// - `Err(QuantorError::SequenceMismatch { kind, index })` with the first index where the elements differ.
+/// - `Err(QuantorError::LengthMismatch { kind, found, expected })` if all shared positions are equal but the lengths differ.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::matches_sequence, error::QuantorResultExt};
+///
+/// let actual = vec!(1, 2, 3, 5);
+///
+/// assert!(matches_sequence(&actual, &[1, 2, 3, 5]).is_ok());
+///
+/// let err = matches_sequence(&actual, &[1, 2, 3, 4]);
+/// assert_eq!(err.failing_index(), Some(3));
+/// assert_eq!(err.unwrap_err().to_string(), "Sequences differ at index 3 for quantifier matches_sequence.");
+///
+/// assert!(matches_sequence(&actual, &[1, 2]).is_err());
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn matches_sequence<'a, 'b, I, J, T>(actual: I, expected: J) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    J: IntoIterator<Item = &'b T>,
+    T: 'a + 'b + PartialEq,
+{
+    let mut actual = actual.into_iter();
+    let mut expected = expected.into_iter();
+    let mut index = 0;
+
+    loop {
+        match (actual.next(), expected.next()) {
+            (Some(a), Some(e)) => {
+                if a != e {
+                    return Err(QuantorError::SequenceMismatch { kind: QuantorKind::SequenceEqual, index });
+                }
+            }
+            (None, None) => return Ok(()),
+            (Some(_), None) => {
+                let found = index + 1 + actual.count();
+                return Err(QuantorError::LengthMismatch { kind: QuantorKind::SequenceEqual, found, expected: index });
+            }
+            (None, Some(_)) => {
+                let expected = index + 1 + expected.count();
+                return Err(QuantorError::LengthMismatch { kind: QuantorKind::SequenceEqual, found: index, expected });
+            }
+        }
+        index += 1;
+    }
+}
+
+/// The maximum number of mismatches listed by [`forall_eq`].
+pub const MISMATCH_LIMIT: usize = 5;
+
+/// Checks whether two sequences are element-wise equal, reporting the mismatching values.
+///
+/// Equivalent to **_|actual| = |expected| ∧ ∀i: actualᵢ = expectedᵢ_**.
+///
+/// Unlike [`matches_sequence`], this scans both sequences completely and records up to
+/// [`MISMATCH_LIMIT`] mismatches in their `Display` form, which makes the error readable for
+/// collections such as `&[&str]`. Positions beyond the end of the shorter sequence count as mismatches.
+/// ## Arguments
+/// - `actual` - The sequence to be checked.
+/// - `expected` - The sequence it should equal.
+/// ## Returns
+/// - `Ok(())` if both sequences have the same length and equal elements.
+/// - `Err(QuantorError::ElementsMismatched { kind, mismatches, total })` otherwise.
+/// ## Example
+/// ```
+/// use quantor::quantifiers::forall_eq;
+///
+/// let actual   = vec!["a", "x", "c"];
+/// let expected = vec!["a", "b", "c", "d"];
+///
+/// let err = forall_eq(&actual, &expected).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "2 element(s) differ for quantifier forall_eq:\n  [1] expected \"b\" found \"x\"\n  [3] expected \"d\" found nothing");
+///
+/// let many = forall_eq(&[0; 8], &[1; 8]).unwrap_err();
+///
+/// assert!(many.to_string().ends_with("\n  ... 3 more"));
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_eq<'a, 'b, I, J, T>(actual: I, expected: J) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a T>,
+    J: IntoIterator<Item = &'b T>,
+    T: 'a + 'b + PartialEq + Display,
+{
+    let mut actual = actual.into_iter();
+    let mut expected = expected.into_iter();
+    let mut mismatches = Vec::new();
+    let mut total = 0;
+    let mut index = 0;
+
+    loop {
+        let (found, wanted) = match (actual.next(), expected.next()) {
+            (None, None) => break,
+            (Some(a), Some(e)) if a == e => {
+                index += 1;
+                continue;
+            }
+            pair => pair,
+        };
+
+        total += 1;
+        if mismatches.len() < MISMATCH_LIMIT {
+            mismatches.push(Mismatch {
+                index,
+                expected: wanted.map(|e| e.to_string()),
+                found: found.map(|a| a.to_string()),
+            });
+        }
+        index += 1;
+    }
+
+    if total == 0 {
+        Ok(())
+    } else {
+        Err(QuantorError::ElementsMismatched { kind: QuantorKind::ForallEq, mismatches, total })
+    }
+}
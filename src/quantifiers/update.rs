@@ -0,0 +1,50 @@
+//! Checks that update elements in place while validating them.
+//!
+//! These functions bridge validation and normalization: each element is visited exactly once,
+//! and may be modified by the same closure that checks it.
+
+use crate::QuantorError;
+
+/// Applies a fallible update to every element, stopping at the first error.
+///
+/// Equivalent to **_∀a ∈ iter: f(a) = Ok_**, where `f` may modify `a`.
+///
+/// Elements are processed in a single pass, in iteration order. When `f` fails, the remaining
+/// elements are left untouched, while the updates already applied to earlier elements are kept.
+/// ## Arguments
+/// - `iter` - The elements to be updated, e.g. `vec.iter_mut()` or `&mut vec`.
+/// - `f` - The update to apply to each element, returning an error if the element is invalid.
+/// ## Returns
+/// - `Ok(())` if `f` succeeded for every element.
+/// - `Err(QuantorError::ElementFailed { index, source })` with the index of the first failing element
+///   and the error returned by `f`.
+/// ## Example
+/// ```
+/// use quantor::{quantifiers::for_each_checked, error::QuantorResultExt};
+///
+/// let mut names = vec![String::from(" ann "), String::from("bob"), String::new(), String::from("eve ")];
+///
+/// let result = for_each_checked(&mut names, |name| {
+///     *name = name.trim().to_string();
+///     if name.is_empty() { Err("empty name".into()) } else { Ok(()) }
+/// });
+///
+/// assert_eq!(result.failing_index(), Some(2));
+/// assert_eq!(result.unwrap_err().to_string(), "Check failed for element at index 2: empty name");
+/// assert_eq!(names, vec!["ann", "bob", "", "eve "]);
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn for_each_checked<'a, I, T: 'a, F>(iter: I, mut f: F) -> Result<(), QuantorError>
+where
+    I: IntoIterator<Item = &'a mut T>,
+    F: FnMut(&mut T) -> Result<(), QuantorError>,
+{
+    for (index, item) in iter.into_iter().enumerate() {
+        if let Err(source) = f(item) {
+            return Err(QuantorError::ElementFailed { index, source: Box::new(source) });
+        }
+    }
+
+    Ok(())
+}
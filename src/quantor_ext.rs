@@ -10,10 +10,12 @@
 //! making it compatible with `Vec<T>`, slices, arrays, and similar types.
 //!
 //! ## Included Quantifiers
-//! - Core: `forall`, `exists`, `none`, `exactly_one`, `all_equal`
-//! - Nested: `forallexists`, `existsforall`
-//! - Structured: `pairwise`, `failing_elements`
-//! - Selection: `select_where`, `select_unique`, `select_duplicates`
+//! - Core: `forall`, `forall_value`, `exists`, `exists_value`, `none`, `implies`, `exactly_one_of`, `exactly_one`, `the_one`, `all_equal`, `all_equal_by`, `all_close`
+//! - Nested: `forallexists`, `existsforall`, `is_subset`, `is_superset`, `disjoint`
+//! - Structured: `pairwise`, `is_sorted`, `is_strictly_sorted`, `is_increasing`, `is_decreasing`, `windowed`, `chunks_forall`, `matches_sequence`, `failing_elements`, `count_transitions`, `runs`
+//! - Selection: `select_where`, `select_where_not`, `indices_where`, `select_unique`, `select_duplicates` (and their `*_cloned` variants), `select_min_by`, `select_max_by`
+//! - Reports: `forall_report`, `none_report`, and the multi-failure `forall_collect`, `none_collect`, `pairwise_collect`
+//! - Combinators: `validate_all`
 //!
 //! Enable the `method-api` feature to activate this module and import it via `quantor::prelude::*`.
 //!
@@ -26,9 +28,9 @@
 //! assert_eq!(xs.select_where(|x| x % 2 == 0), vec![&2]);
 //! ```
 
-use std::hash::Hash;
+use std::{hash::Hash, ops::Sub};
 
-use crate::QuantorError;
+use crate::{combine::SliceCheck, quantifiers::CountBound, report::ForallReport, QuantorError, QuantorErrors};
 /// Extension trait providing method-style quantifier logic over collections.
 ///
 /// This trait enables calling quantifiers like `forall`, `exists`, `none`, and selection
@@ -40,12 +42,22 @@ use crate::QuantorError;
 ///
 /// To use this trait, import it via the `quantor::prelude::*` module or enable the `method-api` feature.
 ///
+/// The element type carries no bounds of its own: only methods that need them, such as the
+/// `*_cloned` selections (`Clone`) or `select_unique` (`Eq + Hash`), require additional traits.
+///
 /// ## Example
 /// ```
 /// use quantor::prelude::*;
 ///
 /// let data = vec![1, 2, 3];
 /// assert!(data.forall(|x| *x < 10).is_ok());
+///
+/// // Element types do not need to implement `Clone`.
+/// struct Conn { open: bool }
+///
+/// let pool = [Conn { open: true }, Conn { open: false }];
+/// assert_eq!(pool.forall(|c| c.open).unwrap_err().to_string(), "Predicate failed for element at index 1 of quantifier forall.");
+/// assert_eq!(pool.select_where(|c| c.open).len(), 1);
 /// ```
 pub trait QuantorExt<T> {
     /// Succeeds if all elements satisfy the predicate.
@@ -59,7 +71,28 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn forall<F>(&self, pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T) -> bool;
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if all elements satisfy the predicate, returning the first counterexample otherwise.
+    ///
+    /// Returns:
+    /// - `Ok(())` if the predicate holds for every element
+    /// - `Err((index, &T))` with the first failing element
+    ///
+    /// Equivalent to **_∀x ∈ self: pred(x)_**.  
+    /// See [`crate::quantifiers::basic::forall_value`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 2, 3];
+    /// assert_eq!(xs.forall_value(|x| *x < 3), Err((2, &3)));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn forall_value<F>(&self, pred: F) -> Result<(), (usize, &T)>
+    where
+        F: FnMut(&T) -> bool;
 
     /// Succeeds if any element satisfies the predicate.
     ///
@@ -72,7 +105,28 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn exists<F>(&self, pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T) -> bool;
+        F: FnMut(&T) -> bool;
+
+    /// Returns the first element that satisfies the predicate.
+    ///
+    /// Returns:
+    /// - `Ok(&T)` with the first match
+    /// - `Err(QuantorError::NoMatch)` if none match
+    ///
+    /// Equivalent to **_∃x ∈ self: pred(x)_**.  
+    /// See [`crate::quantifiers::basic::exists_value`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 4, 6];
+    /// assert_eq!(xs.exists_value(|x| x % 2 == 0), Ok(&4));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn exists_value<F>(&self, pred: F) -> Result<&T, QuantorError>
+    where
+        F: FnMut(&T) -> bool;
 
     /// Succeeds if no element satisfies the predicate.
     ///
@@ -84,6 +138,59 @@ pub trait QuantorExt<T> {
     /// See [`crate::quantifiers::basic::none`] for details.
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn none<F>(&self, pred: F) -> Result<(), QuantorError>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if every element satisfying `premise` also satisfies `conclusion`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if the implication holds for every element
+    /// - `Err(QuantorError::ImplicationViolated { index })` on the first violation
+    ///
+    /// Equivalent to **_∀x ∈ self: premise(x) → conclusion(x)_**.  
+    /// See [`crate::quantifiers::basic::implies`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![2, 3, 4, 6];
+    /// assert!(xs.implies(|x| *x > 3, |x| x % 2 == 0).is_ok());
+    /// assert!(xs.implies(|x| *x > 2, |x| x % 2 == 0).is_err());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn implies<P, Q>(&self, premise: P, conclusion: Q) -> Result<(), QuantorError>
+    where
+        P: FnMut(&T) -> bool,
+        Q: FnMut(&T) -> bool;
+
+    /// Succeeds if every element satisfies exactly one predicate out of a set.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every element matches exactly one predicate
+    /// - `Err(QuantorError::NotExactlyOneOf { index, matched, .. })` for the first element matching zero or several
+    ///
+    /// Equivalent to **_∀x ∈ self: |{p ∈ preds | p(x)}| = 1_**.  
+    /// See [`crate::quantifiers::basic::exactly_one_of`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// let is_negative = |x: &i32| *x < 0;
+    /// let is_zero = |x: &i32| *x == 0;
+    /// let is_positive = |x: &i32| *x > 0;
+    /// let is_even = |x: &i32| x % 2 == 0;
+    ///
+    /// let xs = vec![-3, 0, 7];
+    /// assert!(xs.exactly_one_of(&[&is_negative as &dyn Fn(&i32) -> bool, &is_zero, &is_positive]).is_ok());
+    ///
+    /// let result = xs.exactly_one_of(&[&is_positive as &dyn Fn(&i32) -> bool, &is_even]);
+    /// assert_eq!(result.failing_index(), Some(0));
+    /// assert_eq!(result.match_count(), Some(0));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn exactly_one_of<F>(&self, preds: &[F]) -> Result<(), QuantorError>
     where
         F: Fn(&T) -> bool;
 
@@ -98,20 +205,52 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn exactly_one<F>(&self, pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T) -> bool;
+        F: FnMut(&T) -> bool;
 
-    /// Succeeds if exactly `n` elements satisfy the predicate.
+    /// Returns the single element that satisfies the predicate.
     ///
     /// Returns:
-    /// - `Ok(())` if exactly `n` elements match
-    /// - `Err(QuantorError::ExactlyNFailed { found, expected })` otherwise
+    /// - `Ok(&T)` with the only matching element
+    /// - `Err(QuantorError::EmptyInput { .. })` if the collection is empty
+    /// - `Err(QuantorError::NoMatch { .. })` if no element matches
+    /// - `Err(QuantorError::UnexpectedMatch { index })` with the index of the second match if several match
     ///
-    /// Equivalent to **_|{x ∈ self | pred(x)}| = n_**.  
+    /// Equivalent to **_ιx ∈ self: pred(x)_**.  
+    /// See [`crate::quantifiers::basic::the_one`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, QuantorError};
+    ///
+    /// struct Config { name: &'static str, default: bool }
+    ///
+    /// let configs = vec![
+    ///     Config { name: "dev", default: false },
+    ///     Config { name: "prod", default: true },
+    /// ];
+    ///
+    /// assert_eq!(configs.the_one(|c| c.default).unwrap().name, "prod");
+    /// assert!(matches!(configs.the_one(|c| c.name.is_empty()), Err(QuantorError::NoMatch { .. })));
+    /// assert!(matches!(configs.the_one(|c| !c.name.is_empty()), Err(QuantorError::UnexpectedMatch { index: 1, .. })));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn the_one<F>(&self, pred: F) -> Result<&T, QuantorError>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if exactly `n` elements satisfy the predicate, or if the count lies within the range `n`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if the number of matches satisfies `n`
+    /// - `Err(QuantorError::ExactlyNFailed { found, expected, .. })` otherwise
+    ///
+    /// Equivalent to **_|{x ∈ self | pred(x)}| ∈ n_**.  
     /// See [`crate::quantifiers::basic::exactly_n`] for details.
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
-    fn exactly_n<F>(&self, n: usize, pred: F) -> Result<(), QuantorError>
+    fn exactly_n<N, F>(&self, n: N, pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T) -> bool;
+        N: CountBound,
+        F: FnMut(&T) -> bool;
 
     /// Succeeds if all elements are equal.
     ///
@@ -126,6 +265,49 @@ pub trait QuantorExt<T> {
     where
         T: Eq;
 
+    /// Succeeds if all elements have an equal key.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every key is equal to the key of the first element
+    /// - `Err(QuantorError::NotAllEqual { index })` if any differ
+    ///
+    /// Equivalent to **_∀x, y ∈ self: key(x) = key(y)_**.  
+    /// See [`crate::quantifiers::basic::all_equal_by`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let versions = vec![(1, "a"), (1, "b")];
+    /// assert!(versions.all_equal_by(|r| r.0).is_ok());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn all_equal_by<K, F>(&self, key: F) -> Result<(), QuantorError>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K;
+
+    /// Succeeds if all elements are within `tolerance` of the first element.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every element is close to the first
+    /// - `Err(QuantorError::NotAllEqual { index })` for the first element further away
+    ///
+    /// Equivalent to **_∀x ∈ self: |x - x₀| ≤ tolerance_**.  
+    /// See [`crate::quantifiers::basic::all_close`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let readings = vec![0.5, 0.505, 0.498];
+    /// assert!(readings.all_close(0.01).is_ok());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn all_close(&self, tolerance: T) -> Result<(), QuantorError>
+    where
+        T: Copy + PartialOrd + Sub<Output = T>;
+
     /// Succeeds if for every element in `self`, there exists a matching element in `rhs`
     /// such that the predicate holds.
     ///
@@ -138,7 +320,7 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn forallexists<U, F>(&self, rhs: &[U], pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T, &U) -> bool;
+        F: FnMut(&T, &U) -> bool;
 
     /// Succeeds if there exists an element in `self` such that all elements in `rhs`
     /// satisfy the predicate against it.
@@ -152,7 +334,68 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn existsforall<U, F>(&self, rhs: &[U], pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T, &U) -> bool;
+        F: FnMut(&T, &U) -> bool;
+
+    /// Succeeds if every element in `self` also appears in `rhs`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if each `self[i]` is contained in `rhs`
+    /// - `Err(QuantorError::ForAllExistsFailed { outer_index })` for the first missing element
+    ///
+    /// Equivalent to **_self ⊆ rhs_**.  
+    /// See [`crate::quantifiers::nested::is_subset`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let required = vec!["id", "name"];
+    /// let columns = vec!["id", "name", "email"];
+    ///
+    /// assert!(required.is_subset(&columns).is_ok());
+    /// assert!(columns.is_superset(&required).is_ok());
+    /// assert!(columns.is_subset(&required).is_err());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_subset(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where
+        T: Eq + std::hash::Hash;
+
+    /// Succeeds if every element in `rhs` also appears in `self`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if each `rhs[j]` is contained in `self`
+    /// - `Err(QuantorError::ForAllExistsFailed { outer_index })` for the first missing element of `rhs`
+    ///
+    /// Equivalent to **_self ⊇ rhs_**.  
+    /// See [`crate::quantifiers::nested::is_superset`] for details.
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_superset(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where
+        T: Eq + std::hash::Hash;
+
+    /// Succeeds if no element in `self` appears in `rhs`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if the collections share no element
+    /// - `Err(QuantorError::UnexpectedMatch { index })` for the first element of `self` found in `rhs`
+    ///
+    /// Equivalent to **_self ∩ rhs = ∅_**.  
+    /// See [`crate::quantifiers::nested::disjoint`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// let allowed = vec![80, 443];
+    ///
+    /// assert!(allowed.disjoint(&[22, 23]).is_ok());
+    /// assert_eq!(allowed.disjoint(&[443]).failing_index(), Some(1));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn disjoint(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where
+        T: Eq + std::hash::Hash;
 
     /// Succeeds if the predicate holds for all adjacent pairs.
     ///
@@ -165,7 +408,157 @@ pub trait QuantorExt<T> {
     #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
     fn pairwise<F>(&self, pred: F) -> Result<(), QuantorError>
     where
-        F: Fn(&T, &T) -> bool;
+        F: FnMut(&T, &T) -> bool;
+
+    /// Succeeds if the elements are in non-decreasing order.
+    ///
+    /// Returns:
+    /// - `Ok(())` if `aᵢ ≤ aᵢ₊₁` for every adjacent pair
+    /// - `Err(QuantorError::PairwiseFailed { index })` with the start of the first out-of-order pair
+    ///
+    /// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ ≤ aᵢ₊₁_**.  
+    /// Reports the same error as [`assert_sorted!`](crate::assert_sorted).
+    ///
+    /// **Note**: _Unlike this method, the inherent `<[T]>::is_sorted` returns a `bool`. It takes
+    /// precedence when called on a `&[T]`, so call `QuantorExt::is_sorted(&xs)` there._
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// assert!(vec![1, 2, 2, 5].is_sorted().is_ok());
+    /// assert_eq!(vec![1, 5, 3].is_sorted().failing_index(), Some(1));
+    ///
+    /// let slice: &[i32] = &[3, 1];
+    /// assert!(!slice.is_sorted());
+    /// assert_eq!(QuantorExt::is_sorted(&slice).failing_index(), Some(0));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_sorted(&self) -> Result<(), QuantorError>
+    where
+        T: PartialOrd;
+
+    /// Succeeds if the elements are in strictly increasing order.
+    ///
+    /// Returns:
+    /// - `Ok(())` if `aᵢ < aᵢ₊₁` for every adjacent pair
+    /// - `Err(QuantorError::DuplicateAt { index })` if an element equals its predecessor
+    /// - `Err(QuantorError::DecreaseAt { index })` if an element is smaller than, or incomparable to, its predecessor
+    ///
+    /// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ < aᵢ₊₁_**.  
+    /// See [`crate::quantifiers::structured::strictly_increasing`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, QuantorError};
+    ///
+    /// assert!(vec![1, 4, 9].is_strictly_sorted().is_ok());
+    /// assert!(matches!(vec![1, 4, 4].is_strictly_sorted(), Err(QuantorError::DuplicateAt { index: 2, .. })));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_strictly_sorted(&self) -> Result<(), QuantorError>
+    where
+        T: PartialOrd;
+
+    /// Succeeds if the elements are in non-decreasing order.
+    ///
+    /// An alias of [`is_sorted`](QuantorExt::is_sorted), provided for symmetry with
+    /// [`is_decreasing`](QuantorExt::is_decreasing). Use [`is_strictly_sorted`](QuantorExt::is_strictly_sorted)
+    /// to reject repeated values.
+    ///
+    /// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ ≤ aᵢ₊₁_**.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// assert!([1, 1, 2].is_increasing().is_ok());
+    /// assert!([2, 1].is_increasing().is_err());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_increasing(&self) -> Result<(), QuantorError>
+    where
+        T: PartialOrd;
+
+    /// Succeeds if the elements are in non-increasing order.
+    ///
+    /// Returns:
+    /// - `Ok(())` if `aᵢ ≥ aᵢ₊₁` for every adjacent pair
+    /// - `Err(QuantorError::PairwiseFailed { index })` with the start of the first out-of-order pair
+    ///
+    /// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: aᵢ ≥ aᵢ₊₁_**.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// assert!(vec![9, 4, 4, 1].is_decreasing().is_ok());
+    /// assert_eq!(vec![9, 4, 6].is_decreasing().failing_index(), Some(1));
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn is_decreasing(&self) -> Result<(), QuantorError>
+    where
+        T: PartialOrd;
+
+    /// Succeeds if the predicate holds for every sliding window of `size` adjacent elements.
+    ///
+    /// The predicate receives each window as a `&[&T]` slice of length `size`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every window satisfies the predicate
+    /// - `Err(QuantorError::PairwiseFailed { index })` with the start of the first failing window
+    ///
+    /// Equivalent to **_∀i: pred(aᵢ, …, aᵢ₊ₙ₋₁)_** with `n = size`.  
+    /// See [`crate::quantifiers::structured::windowed`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let data = vec![1, 2, 3, 4];
+    /// assert!(data.windowed(3, |w| w.iter().copied().sum::<i32>() < 10).is_ok());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn windowed<F>(&self, size: usize, pred: F) -> Result<(), QuantorError>
+    where
+        F: FnMut(&[&T]) -> bool;
+
+    /// Succeeds if the predicate holds for every non-overlapping chunk of `size` elements.
+    ///
+    /// The predicate receives each chunk as a `&[&T]` slice; the last chunk may be shorter than `size`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every chunk satisfies the predicate
+    /// - `Err(QuantorError::PairwiseFailed { index })` with the start of the first failing chunk
+    ///
+    /// Equivalent to **_∀k: pred(aₖₙ, …, aₖₙ₊ₙ₋₁)_** with `n = size`.  
+    /// See [`crate::quantifiers::structured::chunks_forall`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let data = vec![1, 1, 2, 2, 3];
+    /// assert!(data.chunks_forall(2, |c| c[0] == c[c.len() - 1]).is_ok());
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn chunks_forall<F>(&self, size: usize, pred: F) -> Result<(), QuantorError>
+    where
+        F: FnMut(&[&T]) -> bool;
+
+    /// Succeeds if `self` is element-wise equal to `expected`.
+    ///
+    /// Returns:
+    /// - `Ok(())` if both have the same length and equal elements
+    /// - `Err(QuantorError::SequenceMismatch { index })` at the first differing element
+    /// - `Err(QuantorError::LengthMismatch { found, expected })` if only the lengths differ
+    ///
+    /// Equivalent to **_|self| = |expected| ∧ ∀i: selfᵢ = expectedᵢ_**.  
+    /// See [`crate::quantifiers::structured::matches_sequence`] for details.
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn matches_sequence(&self, expected: &[T]) -> Result<(), QuantorError>
+    where
+        T: PartialEq;
 
     /// Returns all elements that fail the predicate.
     ///
@@ -174,7 +567,41 @@ pub trait QuantorExt<T> {
     #[must_use]
     fn failing_elements<F>(&self, pred: F) -> Vec<&T>
     where
-        F: Fn(&T) -> bool;
+        F: FnMut(&T) -> bool;
+
+    /// Counts how often the predicate changes its result between adjacent elements.
+    ///
+    /// Equivalent to **_|{(xᵢ, xᵢ₊₁) ∈ self | pred(xᵢ) ≠ pred(xᵢ₊₁)}|_**.  
+    /// See [`crate::quantifiers::structured::count_transitions`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let is_open = vec![false, true, true, false];
+    /// assert_eq!(is_open.count_transitions(|x| *x), 2);
+    /// ```
+    #[must_use]
+    fn count_transitions<F>(&self, pred: F) -> usize
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Returns the runs of equal adjacent elements with their lengths.
+    ///
+    /// Equivalent to the run-length encoding of `self`.  
+    /// See [`crate::quantifiers::structured::runs`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 1, 2, 3, 3, 3];
+    /// assert_eq!(xs.runs(), vec![(&1, 2), (&2, 1), (&3, 3)]);
+    /// ```
+    #[must_use]
+    fn runs(&self) -> Vec<(&T, usize)>
+    where
+        T: PartialEq;
 
     /// Returns all elements that satisfy the predicate.
     ///
@@ -183,7 +610,41 @@ pub trait QuantorExt<T> {
     #[must_use]
     fn select_where<F>(&self, pred: F) -> Vec<&T>
     where
-        F: Fn(&T) -> bool;
+        F: FnMut(&T) -> bool;
+
+    /// Returns all elements that do not satisfy the predicate.
+    ///
+    /// Equivalent to **_{x ∈ self | ¬pred(x)}_**.  
+    /// See [`crate::quantifiers::selection::select_where_not`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 2, 3, 4];
+    /// assert_eq!(xs.select_where_not(|x| x % 2 == 0), vec![&1, &3]);
+    /// ```
+    #[must_use]
+    fn select_where_not<F>(&self, pred: F) -> Vec<&T>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Returns the indices of all elements that satisfy the predicate.
+    ///
+    /// Equivalent to **_{i | pred(xᵢ)}_**.  
+    /// See [`crate::quantifiers::selection::indices_where`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 4, 7, 8];
+    /// assert_eq!(xs.indices_where(|x| x % 2 == 0), vec![1, 3]);
+    /// ```
+    #[must_use]
+    fn indices_where<F>(&self, pred: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool;
 
     /// Returns unique elements that satisfy the predicate.
     ///
@@ -192,7 +653,7 @@ pub trait QuantorExt<T> {
     #[must_use]
     fn select_unique<F>(&self, pred: F) -> Vec<&T>
     where
-        F: Fn(&T) -> bool,
+        F: FnMut(&T) -> bool,
         T: Eq + std::hash::Hash;
 
     /// Returns all elements that appear more than once.
@@ -203,39 +664,314 @@ pub trait QuantorExt<T> {
     fn select_duplicates(&self) -> Vec<&T>
     where
         T: Eq + std::hash::Hash;
+
+    /// Returns owned clones of all elements that satisfy the predicate.
+    ///
+    /// Equivalent to **_{x ∈ self | pred(x)}_**.  
+    /// See [`crate::quantifiers::selection::select_where`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let names = vec![String::from("ann"), String::from("bob")];
+    /// let short: Vec<String> = names.select_where_cloned(|n| n.starts_with('a'));
+    ///
+    /// assert_eq!(short, vec![String::from("ann")]);
+    /// ```
+    #[must_use]
+    fn select_where_cloned<F>(&self, pred: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+        T: Clone;
+
+    /// Returns owned clones of the unique elements that satisfy the predicate.
+    ///
+    /// Equivalent to **_distinct({x ∈ self | pred(x)})_**.  
+    /// See [`crate::quantifiers::selection::select_unique`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let tags = vec![String::from("red"), String::from("blue"), String::from("red")];
+    /// let unique: Vec<String> = tags.select_unique_cloned(|t| t.len() == 3);
+    ///
+    /// assert_eq!(unique, vec![String::from("red")]);
+    /// ```
+    #[must_use]
+    fn select_unique_cloned<F>(&self, pred: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+        T: Clone + Eq + std::hash::Hash;
+
+    /// Returns owned clones of all elements that appear more than once.
+    ///
+    /// Equivalent to **_{x ∈ self | count(x) > 1}_**.  
+    /// See [`crate::quantifiers::selection::select_duplicates`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let emails = vec![String::from("a@x.io"), String::from("b@x.io"), String::from("a@x.io")];
+    /// let repeated: Vec<String> = emails.select_duplicates_cloned();
+    ///
+    /// assert_eq!(repeated, vec![String::from("a@x.io")]);
+    /// ```
+    #[must_use]
+    fn select_duplicates_cloned(&self) -> Vec<T>
+    where
+        T: Clone + Eq + std::hash::Hash;
+
+    /// Returns the first element with the smallest key, or `None` if there is none.
+    ///
+    /// Equivalent to **_argmin({key(x) | x ∈ self})_**.  
+    /// See [`crate::quantifiers::selection::select_min_by`] for details.
+    #[must_use]
+    fn select_min_by<K, F>(&self, key: F) -> Option<&T>
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K;
+
+    /// Returns the first element with the largest key, or `None` if there is none.
+    ///
+    /// Equivalent to **_argmax({key(x) | x ∈ self})_**.  
+    /// See [`crate::quantifiers::selection::select_max_by`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![3, 7, 7, 1];
+    /// assert_eq!(xs.select_max_by(|x| *x), Some(&7));
+    /// assert_eq!(xs.select_min_by(|x| *x), Some(&1));
+    /// ```
+    #[must_use]
+    fn select_max_by<K, F>(&self, key: F) -> Option<&T>
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K;
+
+    /// Reports how many elements satisfy the predicate, and which ones fail it.
+    ///
+    /// Returns:
+    /// - A [`ForallReport`] with the pass count, every failing `(index, element)`, and the first failing index
+    ///
+    /// Equivalent to **_{x ∈ self | ¬pred(x)}_**.  
+    /// See [`crate::report::forall_report`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::prelude::*;
+    ///
+    /// let xs = vec![1, 2, 3];
+    /// let report = xs.forall_report(|x| *x < 3);
+    ///
+    /// assert_eq!(report.passed, 2);
+    /// assert_eq!(report.first_failure, Some(2));
+    /// ```
+    #[must_use]
+    fn forall_report<F>(&self, pred: F) -> ForallReport<'_, T>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Reports how many elements fail the predicate, and which ones satisfy it.
+    ///
+    /// Returns:
+    /// - A [`ForallReport`] with the count of non-matching elements, every matching `(index, element)`, and the first matching index
+    ///
+    /// Equivalent to **_{x ∈ self | pred(x)}_**.  
+    /// See [`crate::report::none_report`] for details.
+    #[must_use]
+    fn none_report<F>(&self, pred: F) -> ForallReport<'_, T>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if all elements satisfy the predicate, collecting every failure otherwise.
+    ///
+    /// [`forall`](QuantorExt::forall) stops at the first counterexample; this evaluates every element
+    /// and reports all of them. Prefer `forall` for validation and this method when all offenders
+    /// should be shown at once.
+    ///
+    /// [`forall_report`](QuantorExt::forall_report) also evaluates every element, but returns a
+    /// [`ForallReport`] with the failing *values*, for logging and inspection. The `*_collect`
+    /// methods (this one, [`none_collect`](QuantorExt::none_collect) and
+    /// [`pairwise_collect`](QuantorExt::pairwise_collect)) return the failures as [`QuantorErrors`]
+    /// instead, for propagating with `?`; they are named `*_collect` because the `*_report` names
+    /// belong to the `ForallReport` family.
+    ///
+    /// Returns:
+    /// - `Ok(())` if all elements match
+    /// - `Err(QuantorErrors)` with a `QuantorError::PredicateFailed` per failing element
+    ///
+    /// Equivalent to **_∀x ∈ self: pred(x)_**.  
+    /// See [`crate::quantifiers::basic::forall_collect`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// let xs = vec![1, 5, 2, 7];
+    ///
+    /// assert_eq!(xs.forall(|x| *x < 5).failing_index(), Some(1));
+    /// assert_eq!(xs.forall_collect(|x| *x < 5).failing_indices(), vec![1, 3]);
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn forall_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if no element satisfies the predicate, collecting every match otherwise.
+    ///
+    /// The multi-failure counterpart of [`none`](QuantorExt::none).
+    ///
+    /// Unlike [`none_report`](QuantorExt::none_report), it returns errors rather than the matching
+    /// values; see [`forall_collect`](QuantorExt::forall_collect) for how the two families differ.
+    ///
+    /// Returns:
+    /// - `Ok(())` if no element matches
+    /// - `Err(QuantorErrors)` with a `QuantorError::UnexpectedMatch` per matching element
+    ///
+    /// Equivalent to **_∀x ∈ self: ¬pred(x)_**.  
+    /// See [`crate::quantifiers::basic::none_collect`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// let xs = vec![-1, 2, -3];
+    ///
+    /// assert_eq!(xs.none_collect(|x| *x < 0).failing_indices(), vec![0, 2]);
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn none_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where
+        F: FnMut(&T) -> bool;
+
+    /// Succeeds if the predicate holds for all adjacent pairs, collecting every failing pair otherwise.
+    ///
+    /// The multi-failure counterpart of [`pairwise`](QuantorExt::pairwise). See
+    /// [`forall_collect`](QuantorExt::forall_collect) for how the `*_collect` methods differ from
+    /// the `*_report` ones.
+    ///
+    /// Returns:
+    /// - `Ok(())` if all adjacent pairs match
+    /// - `Err(QuantorErrors)` with a `QuantorError::PairwiseFailed` per failing pair
+    ///
+    /// Equivalent to **_∀(aᵢ, aᵢ₊₁) ∈ self: pred(aᵢ, aᵢ₊₁)_**.  
+    /// See [`crate::quantifiers::structured::pairwise_collect`] for details.
+    ///
+    /// ## Example
+    /// ```
+    /// use quantor::{prelude::*, error::QuantorResultExt};
+    ///
+    /// let xs = vec![1, 3, 2, 4, 0];
+    ///
+    /// assert_eq!(xs.pairwise_collect(|a, b| a < b).failing_indices(), vec![1, 3]);
+    /// ```
+    #[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+    fn pairwise_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where
+        F: FnMut(&T, &T) -> bool;
+
+    /// Runs a list of whole-slice checks and collects every failure.
+    ///
+    /// Returns:
+    /// - `Ok(())` if every check succeeds, including when `checks` is empty
+    /// - `Err(QuantorErrors)` containing the error of every failing check, in the order of `checks`
+    ///
+    /// Equivalent to **_∀c ∈ checks: c(self) = Ok_**.  
+    /// See [`crate::combine::all_of`] for details.
+    ///
+    /// ## Example
+    /// Functions and closures with the signature `Fn(&[T]) -> Result<(), QuantorError>` can be listed
+    /// directly; the references coerce to `&dyn Fn` at the call site. To store a reusable set of
+    /// invariants, annotate them with [`SliceCheck`]:
+    /// ```
+    /// use quantor::{prelude::*, QuantorError};
+    ///
+    /// fn non_negative(xs: &[i32]) -> Result<(), QuantorError> {
+    ///     forall(xs, |x| *x >= 0)
+    /// }
+    ///
+    /// let readings = vec![3, -1, 2];
+    ///
+    /// let errors = readings.validate_all(&[&non_negative, &|xs| pairwise(xs, |a, b| a <= b)]).unwrap_err();
+    /// assert_eq!(errors.len(), 2);
+    ///
+    /// let invariants: [SliceCheck<i32>; 2] = [
+    ///     &non_negative,
+    ///     &|xs| exists(xs, |x| *x == 0),
+    /// ];
+    ///
+    /// assert!(vec![0, 1, 2].validate_all(&invariants).is_ok());
+    /// assert_eq!(readings.validate_all(&invariants).unwrap_err().len(), 2);
+    /// ```
+    fn validate_all(&self, checks: &[SliceCheck<'_, T>]) -> Result<(), QuantorErrors>;
 }
 
 impl<T, S> QuantorExt<T> for S
 where
-    T: Clone,
     S: AsRef<[T]>, {
     #[inline]
     fn forall<F>(&self, pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T) -> bool {
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::basic::forall(self.as_ref(), pred)
     }
 
+    #[inline]
+    fn forall_value<F>(&self, pred: F) -> Result<(), (usize, &T)>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::basic::forall_value(self.as_ref(), pred)
+    }
+
     #[inline]
     fn exists<F>(&self, pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T) -> bool {
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::basic::exists(self.as_ref(), pred)
     }
 
+    #[inline]
+    fn exists_value<F>(&self, pred: F) -> Result<&T, QuantorError>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::basic::exists_value(self.as_ref(), pred)
+    }
+
     #[inline]
     fn none<F>(&self, pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T) -> bool {
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::basic::none(self.as_ref(), pred)
     }
 
     #[inline]
-    fn exactly_one<F>(&self, pred: F) -> Result<(), QuantorError>
+    fn implies<P, Q>(&self, premise: P, conclusion: Q) -> Result<(), QuantorError>
+    where P: FnMut(&T) -> bool,
+          Q: FnMut(&T) -> bool {
+        crate::quantifiers::basic::implies(self.as_ref(), premise, conclusion)
+    }
+
+    #[inline]
+    fn exactly_one_of<F>(&self, preds: &[F]) -> Result<(), QuantorError>
     where F: Fn(&T) -> bool {
+        crate::quantifiers::basic::exactly_one_of(self.as_ref(), preds)
+    }
+
+    #[inline]
+    fn exactly_one<F>(&self, pred: F) -> Result<(), QuantorError>
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::basic::exactly_one(self.as_ref(), pred)
     }
 
     #[inline]
-    fn exactly_n<F>(&self, n: usize, pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T) -> bool {
+    fn the_one<F>(&self, pred: F) -> Result<&T, QuantorError>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::basic::the_one(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn exactly_n<N, F>(&self, n: N, pred: F) -> Result<(), QuantorError>
+    where N: CountBound, F: FnMut(&T) -> bool {
         crate::quantifiers::basic::exactly_n(self.as_ref(), n, pred)
     }
 
@@ -245,39 +981,136 @@ where
         crate::quantifiers::basic::all_equal(self.as_ref())
     }
 
+    #[inline]
+    fn all_equal_by<K, F>(&self, key: F) -> Result<(), QuantorError>
+    where K: PartialEq,
+          F: FnMut(&T) -> K {
+        crate::quantifiers::basic::all_equal_by(self.as_ref(), key)
+    }
+
+    #[inline]
+    fn all_close(&self, tolerance: T) -> Result<(), QuantorError>
+    where T: Copy + PartialOrd + Sub<Output = T> {
+        crate::quantifiers::basic::all_close(self.as_ref(), tolerance)
+    }
+
     #[inline]
     fn forallexists<U, F>(&self, rhs: &[U], pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T, &U) -> bool {
+    where F: FnMut(&T, &U) -> bool {
         crate::quantifiers::nested::forallexists(self.as_ref(), rhs.iter(), pred)
     }
 
     #[inline]
     fn existsforall<U, F>(&self, rhs: &[U], pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T, &U) -> bool {
+    where F: FnMut(&T, &U) -> bool {
         crate::quantifiers::nested::existsforall(self.as_ref(), rhs.iter(), pred)
     }
 
+    #[inline]
+    fn is_subset(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where T: Eq + Hash {
+        crate::quantifiers::nested::is_subset(self.as_ref(), rhs.iter())
+    }
+
+    #[inline]
+    fn is_superset(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where T: Eq + Hash {
+        crate::quantifiers::nested::is_superset(self.as_ref(), rhs.iter())
+    }
+
+    #[inline]
+    fn disjoint(&self, rhs: &[T]) -> Result<(), QuantorError>
+    where T: Eq + Hash {
+        crate::quantifiers::nested::disjoint(self.as_ref(), rhs.iter())
+    }
+
     #[inline]
     fn pairwise<F>(&self, pred: F) -> Result<(), QuantorError>
-    where F: Fn(&T, &T) -> bool {
+    where F: FnMut(&T, &T) -> bool {
         crate::quantifiers::structured::pairwise(self.as_ref(), pred)
     }
 
+    #[inline]
+    fn is_sorted(&self) -> Result<(), QuantorError>
+    where T: PartialOrd {
+        crate::quantifiers::structured::pairwise(self.as_ref(), |a, b| a <= b)
+    }
+
+    #[inline]
+    fn is_strictly_sorted(&self) -> Result<(), QuantorError>
+    where T: PartialOrd {
+        crate::quantifiers::structured::strictly_increasing(self.as_ref())
+    }
+
+    #[inline]
+    fn is_increasing(&self) -> Result<(), QuantorError>
+    where T: PartialOrd {
+        self.is_sorted()
+    }
+
+    #[inline]
+    fn is_decreasing(&self) -> Result<(), QuantorError>
+    where T: PartialOrd {
+        crate::quantifiers::structured::pairwise(self.as_ref(), |a, b| a >= b)
+    }
+
+    #[inline]
+    fn windowed<F>(&self, size: usize, pred: F) -> Result<(), QuantorError>
+    where F: FnMut(&[&T]) -> bool {
+        crate::quantifiers::structured::windowed(self.as_ref(), size, pred)
+    }
+
+    #[inline]
+    fn chunks_forall<F>(&self, size: usize, pred: F) -> Result<(), QuantorError>
+    where F: FnMut(&[&T]) -> bool {
+        crate::quantifiers::structured::chunks_forall(self.as_ref(), size, pred)
+    }
+
+    #[inline]
+    fn matches_sequence(&self, expected: &[T]) -> Result<(), QuantorError>
+    where T: PartialEq {
+        crate::quantifiers::structured::matches_sequence(self.as_ref(), expected)
+    }
+
     #[inline]
     fn failing_elements<F>(&self, pred: F) -> Vec<&T>
-    where F: Fn(&T) -> bool {
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::structured::failing_elements(self.as_ref(), pred)
     }
 
+    #[inline]
+    fn count_transitions<F>(&self, pred: F) -> usize
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::structured::count_transitions(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn runs(&self) -> Vec<(&T, usize)>
+    where T: PartialEq {
+        crate::quantifiers::structured::runs(self.as_ref())
+    }
+
     #[inline]
     fn select_where<F>(&self, pred: F) -> Vec<&T>
-    where F: Fn(&T) -> bool {
+    where F: FnMut(&T) -> bool {
         crate::quantifiers::selection::select_where(self.as_ref(), pred)
     }
 
+    #[inline]
+    fn select_where_not<F>(&self, pred: F) -> Vec<&T>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::selection::select_where_not(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn indices_where<F>(&self, pred: F) -> Vec<usize>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::selection::indices_where(self.as_ref(), pred)
+    }
+
     #[inline]
     fn select_unique<F>(&self, pred: F) -> Vec<&T>
-    where F: Fn(&T) -> bool,
+    where F: FnMut(&T) -> bool,
           T: Eq + Hash {
         crate::quantifiers::selection::select_unique(self.as_ref(), pred)
     }
@@ -287,4 +1120,73 @@ where
     where T: Eq + Hash {
         crate::quantifiers::selection::select_duplicates(self.as_ref())
     }
+
+    #[inline]
+    fn select_where_cloned<F>(&self, pred: F) -> Vec<T>
+    where F: FnMut(&T) -> bool,
+          T: Clone {
+        self.select_where(pred).into_iter().cloned().collect()
+    }
+
+    #[inline]
+    fn select_unique_cloned<F>(&self, pred: F) -> Vec<T>
+    where F: FnMut(&T) -> bool,
+          T: Clone + Eq + Hash {
+        self.select_unique(pred).into_iter().cloned().collect()
+    }
+
+    #[inline]
+    fn select_duplicates_cloned(&self) -> Vec<T>
+    where T: Clone + Eq + Hash {
+        self.select_duplicates().into_iter().cloned().collect()
+    }
+
+    #[inline]
+    fn select_min_by<K, F>(&self, key: F) -> Option<&T>
+    where K: PartialOrd,
+          F: FnMut(&T) -> K {
+        crate::quantifiers::selection::select_min_by(self.as_ref(), key)
+    }
+
+    #[inline]
+    fn select_max_by<K, F>(&self, key: F) -> Option<&T>
+    where K: PartialOrd,
+          F: FnMut(&T) -> K {
+        crate::quantifiers::selection::select_max_by(self.as_ref(), key)
+    }
+
+    #[inline]
+    fn forall_report<F>(&self, pred: F) -> ForallReport<'_, T>
+    where F: FnMut(&T) -> bool {
+        crate::report::forall_report(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn none_report<F>(&self, pred: F) -> ForallReport<'_, T>
+    where F: FnMut(&T) -> bool {
+        crate::report::none_report(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn forall_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::basic::forall_collect(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn none_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where F: FnMut(&T) -> bool {
+        crate::quantifiers::basic::none_collect(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn pairwise_collect<F>(&self, pred: F) -> Result<(), QuantorErrors>
+    where F: FnMut(&T, &T) -> bool {
+        crate::quantifiers::structured::pairwise_collect(self.as_ref(), pred)
+    }
+
+    #[inline]
+    fn validate_all(&self, checks: &[SliceCheck<'_, T>]) -> Result<(), QuantorErrors> {
+        crate::combine::all_of(checks.iter().map(|check| check(self.as_ref())))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! # quantor: Reports
+//!
+//! This module provides [`ForallReport`], a diagnostic summary of a quantifier run. Unlike the
+//! `Result`-returning quantifiers, which stop at the first violation, the report functions
+//! evaluate every element and record all of the failures.
+//!
+//! Reports are meant for logging and inspection. The quantifiers in [`crate::quantifiers`]
+//! remain the primary API for validation.
+//!
+//! ## Example
+//! ```
+//! use quantor::report::forall_report;
+//!
+//! let nums = vec![1, 2, 3, 4];
+//! let report = forall_report(&nums, |x| x % 2 == 0);
+//!
+//! assert_eq!(report.passed, 2);
+//! assert_eq!(report.failed, vec![(0, &1), (2, &3)]);
+//! assert_eq!(report.first_failure, Some(0));
+//! ```
+
+use std::fmt;
+
+/// A summary of how many elements passed a check, and which ones failed.
+///
+/// Produced by [`forall_report`] and [`none_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForallReport<'a, T> {
+    /// The number of elements that passed the check.
+    pub passed: usize,
+    /// The index and value of every element that failed the check, in order.
+    pub failed: Vec<(usize, &'a T)>,
+    /// The index of the first failing element, if any.
+    pub first_failure: Option<usize>,
+}
+
+impl<T> ForallReport<'_, T> {
+    /// Returns `true` if no element failed the check.
+    #[inline]
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Returns the total number of elements that were checked.
+    #[inline]
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.passed + self.failed.len()
+    }
+}
+
+impl<T> fmt::Display for ForallReport<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} elements passed", self.passed, self.total())?;
+        if let Some(index) = self.first_failure {
+            write!(f, ", first failure at index {}", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// Evaluates the predicate on every element and reports which elements fail it.
+///
+/// Equivalent to **_{x ∈ iter | ¬pred(x)}_**, together with the number of passing elements.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - A [`ForallReport`] listing every element for which the predicate returned `false`.
+/// ## Example
+/// ```
+/// use quantor::report::forall_report;
+///
+/// let nums = vec![2, 4, 5];
+/// let report = forall_report(&nums, |x| x % 2 == 0);
+///
+/// assert!(!report.is_ok());
+/// assert_eq!(report.to_string(), "2/3 elements passed, first failure at index 2");
+/// ```
+#[inline]
+#[must_use]
+pub fn forall_report<'a, I, T: 'a, F>(iter: I, mut pred: F) -> ForallReport<'a, T>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    let mut passed = 0;
+    let mut failed = Vec::new();
+
+    for (i, item) in iter.into_iter().enumerate() {
+        if pred(item) {
+            passed += 1;
+        } else {
+            failed.push((i, item));
+        }
+    }
+
+    let first_failure = failed.first().map(|(i, _)| *i);
+    ForallReport { passed, failed, first_failure }
+}
+
+/// Evaluates the predicate on every element and reports which elements satisfy it.
+///
+/// Equivalent to **_{x ∈ iter | pred(x)}_**, together with the number of non-matching elements.
+///
+/// An element fails this check when the predicate returns `true` for it.
+/// ## Arguments
+/// - `iter` - The collection to be checked.
+/// - `pred` - The predicate to test each element against.
+/// ## Returns
+/// - A [`ForallReport`] listing every element for which the predicate returned `true`.
+/// ## Example
+/// ```
+/// use quantor::report::none_report;
+///
+/// let nums = vec![1, -2, 3, -4];
+/// let report = none_report(&nums, |x| *x < 0);
+///
+/// assert_eq!(report.passed, 2);
+/// assert_eq!(report.failed, vec![(1, &-2), (3, &-4)]);
+/// ```
+#[inline]
+#[must_use]
+pub fn none_report<'a, I, T: 'a, F>(iter: I, mut pred: F) -> ForallReport<'a, T>
+where
+    I: IntoIterator<Item = &'a T>,
+    F: FnMut(&T) -> bool,
+{
+    forall_report(iter, |x| !pred(x))
+}
@@ -0,0 +1,95 @@
+//! # quantor: Tracing
+//!
+//! This module provides [`TraceExt`], a thin layer that reports quantifier failures as
+//! [`tracing`](https://docs.rs/tracing) events. The quantifier functions themselves stay pure;
+//! events are only emitted where `.traced()` is called on a result.
+//!
+//! Enable the `tracing` feature to activate this module. When the feature is off, nothing
+//! from this module is compiled.
+//!
+//! ## Spans
+//! Events are emitted in the current span, so a check can be attributed to a named step by
+//! evaluating it inside a span:
+//!
+//! ```
+//! use quantor::{forall, trace::TraceExt};
+//!
+//! let ports = vec![80, 443];
+//!
+//! let result = tracing::info_span!("validate_ports")
+//!     .in_scope(|| forall(&ports, |p| *p <= 65535).traced());
+//!
+//! assert!(result.is_ok());
+//! ```
+
+use crate::{error::error_index, QuantorError};
+
+/// Extension trait emitting a `tracing` event when a quantifier result is an error.
+///
+/// The event is emitted at `WARN` level with the structured fields `kind` (the quantifier name),
+/// `index` (the failing index, if any) and `error` (the full message).
+pub trait TraceExt {
+    /// Emits a `tracing::warn!` event if `self` is an error, and returns `self` unchanged.
+    ///
+    /// ## Example
+    /// A minimal subscriber that records the fields of every event shows what is emitted:
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use quantor::{forall, trace::TraceExt, error::QuantorResultExt};
+    /// use tracing::{field::{Field, Visit}, span, Event, Metadata, Subscriber};
+    ///
+    /// struct Capture(Arc<Mutex<Vec<String>>>);
+    ///
+    /// struct Fields<'a>(&'a mut Vec<String>);
+    ///
+    /// impl Visit for Fields<'_> {
+    ///     fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+    ///         self.0.push(format!("{}={:?}", field.name(), value));
+    ///     }
+    /// }
+    ///
+    /// impl Subscriber for Capture {
+    ///     fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+    ///     fn new_span(&self, _: &span::Attributes<'_>) -> span::Id { span::Id::from_u64(1) }
+    ///     fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+    ///     fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    ///     fn enter(&self, _: &span::Id) {}
+    ///     fn exit(&self, _: &span::Id) {}
+    ///
+    ///     fn event(&self, event: &Event<'_>) {
+    ///         let mut fields = Vec::new();
+    ///         event.record(&mut Fields(&mut fields));
+    ///         self.0.lock().unwrap().push(format!("{} {}", event.metadata().level(), fields.join(" ")));
+    ///     }
+    /// }
+    ///
+    /// let events = Arc::new(Mutex::new(Vec::new()));
+    /// let nums = vec![1, 2, 3];
+    ///
+    /// let result = tracing::subscriber::with_default(Capture(events.clone()), || {
+    ///     forall(&nums, |x| *x < 3).traced()
+    /// });
+    ///
+    /// assert_eq!(result.failing_index(), Some(2));
+    /// assert_eq!(*events.lock().unwrap(), vec![
+    ///     "WARN message=quantifier failed kind=forall index=Some(2) \
+    ///      error=Predicate failed for element at index 2 of quantifier forall.".to_string(),
+    /// ]);
+    ///
+    /// tracing::subscriber::with_default(Capture(events.clone()), || forall(&nums, |x| *x < 4).traced()).unwrap();
+    /// assert_eq!(events.lock().unwrap().len(), 1);
+    /// ```
+    fn traced(self) -> Self;
+}
+
+impl<T> TraceExt for Result<T, QuantorError> {
+    #[inline]
+    fn traced(self) -> Self {
+        if let Err(e) = &self {
+            tracing::warn!(kind = %e.kind(), index = ?error_index(e), error = %e, "quantifier failed");
+        }
+
+        self
+    }
+}
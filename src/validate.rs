@@ -0,0 +1,110 @@
+//! # quantor: Validation
+//!
+//! This module provides the [`Validate`] trait for types that check their own invariants using
+//! quantor primitives, and [`forall_valid`] for checking whole collections of such types at once.
+//!
+//! Together with [`all_of`](crate::combine::all_of), this turns the quantifiers into a lightweight
+//! validation framework: each type lists its rules once, and collections report every failure
+//! together with the index of the offending element.
+//!
+//! ## Example
+//! ```
+//! use quantor::{forall, exists, combine::all_of, validate::{forall_valid, Validate}, QuantorErrors};
+//!
+//! struct Order {
+//!     quantities: Vec<u32>,
+//! }
+//!
+//! impl Validate for Order {
+//!     fn validate(&self) -> Result<(), QuantorErrors> {
+//!         all_of([
+//!             exists(&self.quantities, |_| true),
+//!             forall(&self.quantities, |q| *q > 0),
+//!         ])
+//!     }
+//! }
+//!
+//! let orders = vec![
+//!     Order { quantities: vec![1, 2] },
+//!     Order { quantities: vec![] },
+//!     Order { quantities: vec![3, 0] },
+//! ];
+//!
+//! let errors = forall_valid(&orders).unwrap_err();
+//!
+//! assert_eq!(errors.len(), 2);
+//! assert!(errors.to_string().contains("Check failed for element at index 2: Predicate failed for element at index 1"));
+//! ```
+
+use crate::{QuantorError, QuantorErrors};
+
+/// A type that can check its own invariants.
+///
+/// Implementations typically combine quantifiers with [`all_of`](crate::combine::all_of), so that
+/// every broken rule is reported rather than only the first one.
+pub trait Validate {
+    /// Checks the invariants of `self`.
+    /// ## Returns
+    /// - `Ok(())` if all invariants hold.
+    /// - `Err(QuantorErrors)` containing every violated invariant otherwise.
+    fn validate(&self) -> Result<(), QuantorErrors>;
+}
+
+impl<T: Validate + ?Sized> Validate for &T {
+    #[inline]
+    fn validate(&self) -> Result<(), QuantorErrors> {
+        (**self).validate()
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    #[inline]
+    fn validate(&self) -> Result<(), QuantorErrors> {
+        (**self).validate()
+    }
+}
+
+/// Validates every element and collects all failures.
+///
+/// Equivalent to **_∀a ∈ iter: validate(a) = Ok_**.
+///
+/// Every element is validated, even after a failure.
+/// ## Arguments
+/// - `iter` - The collection of values to be validated.
+/// ## Returns
+/// - `Ok(())` if every element is valid, including when `iter` is empty.
+/// - `Err(QuantorErrors)` with one `QuantorError::ElementFailed { index, source }` per violated
+///   invariant, in order of the elements and then of the element's own errors.
+/// ## Example
+/// ```
+/// use quantor::{forall, error::QuantorResultExt, validate::{forall_valid, Validate}, QuantorErrors};
+///
+/// struct Port(u32);
+///
+/// impl Validate for Port {
+///     fn validate(&self) -> Result<(), QuantorErrors> {
+///         forall([&self.0], |p| *p <= 65535).map_err(QuantorErrors::from)
+///     }
+/// }
+///
+/// let ports = vec![Port(80), Port(70000), Port(443), Port(99999)];
+///
+/// assert_eq!(forall_valid(&ports).failing_indices(), vec![1, 3]);
+/// assert!(forall_valid(&ports[..1]).is_ok());
+/// ```
+#[inline]
+#[must_use = "Quantifier results must be checked. Use `.is_ok()` or `?` to handle them."]
+pub fn forall_valid<'a, I, T>(iter: I) -> Result<(), QuantorErrors>
+where
+    I: IntoIterator<Item = &'a T>,
+    T: Validate + ?Sized + 'a,
+{
+    iter.into_iter()
+        .enumerate()
+        .filter_map(|(index, item)| item.validate().err().map(|errors| (index, errors)))
+        .flat_map(|(index, errors)| {
+            errors.into_iter().map(move |e| QuantorError::ElementFailed { index, source: Box::new(e) })
+        })
+        .collect::<QuantorErrors>()
+        .into_result()
+}